@@ -0,0 +1,76 @@
+use cattocol::{display_width_no_esc, CatToCol};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::iter;
+use strip_ansi_escapes::strip;
+
+fn colored_table(rows: usize) -> (String, String) {
+    let first_txt = (0..rows)
+        .map(|row| format!("\x1b[3{}mrow {row}\x1b[0m label\n", row % 8))
+        .collect::<String>();
+    let second_txt = (0..rows)
+        .map(|row| format!("\x1b[1mvalue\x1b[0m {row}\n"))
+        .collect::<String>();
+    (first_txt, second_txt)
+}
+
+fn line_len_no_esc(text: &str) -> usize {
+    std::str::from_utf8(&strip(text).unwrap())
+        .unwrap()
+        .lines()
+        .map(|line| line.chars().count())
+        .max()
+        .unwrap_or(0)
+}
+
+/// The former implementation, stripping the left line on every row.
+fn per_row_strip<'a>(str_one: &'a str, str_two: &'a str) -> impl Iterator<Item = &'a str> {
+    let max_line_one = line_len_no_esc(str_one);
+
+    str_one.lines().zip(str_two.lines()).flat_map(move |item| {
+        let just_len = max_line_one - line_len_no_esc(item.0);
+        iter::once(item.0)
+            .chain(iter::repeat_n(" ", just_len + 1))
+            .chain(iter::once(item.1))
+            .chain(iter::once("\n"))
+    })
+}
+
+fn bench_combine_col_esc(c: &mut Criterion) {
+    let (first_txt, second_txt) = colored_table(1000);
+    let cattocol = CatToCol::new().repeat(1);
+
+    c.bench_function("per_row_strip", |b| {
+        b.iter(|| per_row_strip(black_box(&first_txt), black_box(&second_txt)).count())
+    });
+    c.bench_function("combine_col_esc", |b| {
+        b.iter(|| {
+            cattocol
+                .combine_col_esc(black_box(&first_txt), black_box(&second_txt))
+                .count()
+        })
+    });
+}
+
+fn bench_display_width_no_esc(c: &mut Criterion) {
+    let (first_txt, _) = colored_table(1000);
+
+    c.bench_function("strip_width", |b| {
+        b.iter(|| {
+            black_box(&first_txt)
+                .lines()
+                .map(line_len_no_esc)
+                .sum::<usize>()
+        })
+    });
+    c.bench_function("display_width_no_esc", |b| {
+        b.iter(|| {
+            black_box(&first_txt)
+                .lines()
+                .map(display_width_no_esc)
+                .sum::<usize>()
+        })
+    });
+}
+
+criterion_group!(benches, bench_combine_col_esc, bench_display_width_no_esc);
+criterion_main!(benches);
@@ -0,0 +1,40 @@
+use cattocol::{CatToCol, WidthMode};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+fn ascii_log(rows: usize) -> (String, String) {
+    let first_txt = (0..rows)
+        .map(|row| {
+            format!(
+                "2024-01-{:02} 12:{:02}:00 host-{row}\n",
+                row % 28 + 1,
+                row % 60
+            )
+        })
+        .collect::<String>();
+    let second_txt = (0..rows)
+        .map(|row| format!("GET /index.html?page={row} 200\n"))
+        .collect::<String>();
+    (first_txt, second_txt)
+}
+
+fn bench_width_mode(c: &mut Criterion) {
+    let (first_txt, second_txt) = ascii_log(1000);
+
+    for (name, width_mode) in [
+        ("unicode", WidthMode::Unicode),
+        ("chars", WidthMode::Chars),
+        ("bytes", WidthMode::Bytes),
+    ] {
+        let cattocol = CatToCol::new().repeat(1).width_mode(width_mode);
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                cattocol
+                    .combine_col(black_box(&first_txt), black_box(&second_txt))
+                    .count()
+            })
+        });
+    }
+}
+
+criterion_group!(benches, bench_width_mode);
+criterion_main!(benches);
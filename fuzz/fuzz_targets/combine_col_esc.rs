@@ -0,0 +1,27 @@
+#![no_main]
+
+use cattocol::{Align, CatToCol};
+use libfuzzer_sys::fuzz_target;
+
+// The first byte splits the rest into the two texts.
+fuzz_target!(|data: &[u8]| {
+    let Some((&split, data)) = data.split_first() else {
+        return;
+    };
+    let (bytes_one, bytes_two) = data.split_at(usize::from(split).min(data.len()));
+    let str_one = String::from_utf8_lossy(bytes_one);
+    let str_two = String::from_utf8_lossy(bytes_two);
+
+    for cat_to_col in [
+        CatToCol::new().repeat(1),
+        CatToCol::new().repeat(1).wrap_width(4),
+        CatToCol::new().repeat(1).boxed(true).align(Align::Right),
+    ] {
+        let Ok(iter) = cat_to_col.try_combine_col_esc(&str_one, &str_two) else {
+            continue;
+        };
+        for fragment in iter {
+            let _ = fragment;
+        }
+    }
+});
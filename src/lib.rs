@@ -42,10 +42,22 @@
 //! ```
 
 #[doc = include_str!("../README.md")]
-use smallstr::SmallString;
-use std::cmp::min;
+use std::borrow::Cow;
+use std::cmp::{max, min};
+use std::fmt;
+use std::io::{self, Write};
 use std::iter;
-use strip_ansi_escapes::strip;
+use std::mem;
+use std::ops::Range;
+use std::vec;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+const SGR_RESET: &str = "\x1b[0m";
+const DIGITS: &str = "0123456789";
+const NBSP: &str = "\u{a0}";
+const CARETS: &str = "@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_?";
+const WRITE_BUF_LEN: usize = 8 * 1024;
 
 impl Default for CatToCol {
     fn default() -> Self {
@@ -53,26 +65,335 @@ impl Default for CatToCol {
     }
 }
 
+/// An error of an impossible CatToCol configuration.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CatToColError {
+    /// The fill is a control character and would break the rows.
+    ControlFill,
+    /// The fill is not a single grapheme cluster of one cell.
+    FillWidth,
+    /// The wrap width is zero, no character fits into a row.
+    ZeroWrapWidth,
+    /// The tab stop is zero, a tab jumps nowhere.
+    ZeroTabStop,
+}
+
+impl fmt::Display for CatToColError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ControlFill => write!(f, "the fill is a control character"),
+            Self::FillWidth => write!(f, "the fill is not a single cell"),
+            Self::ZeroWrapWidth => write!(f, "the wrap width is zero"),
+            Self::ZeroTabStop => write!(f, "the tab stop is zero"),
+        }
+    }
+}
+
+impl std::error::Error for CatToColError {}
+
+/// The order of the columns on a row.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    /// The first text is the left column.
+    #[default]
+    Ltr,
+    /// The first text is the right column.
+    Rtl,
+}
+
+/// The rows omitted from the combined texts.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EmptyPolicy {
+    /// No row is omitted.
+    #[default]
+    Never,
+    /// Rows with both cells empty are omitted.
+    BothEmpty,
+    /// Rows with the right cell empty are omitted.
+    RightEmpty,
+    /// Rows with any cell empty are omitted.
+    AnyEmpty,
+}
+
+/// The rows after the end of the shorter text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TailPolicy {
+    /// The cells of the shorter text are empty.
+    #[default]
+    Blank,
+    /// The cells of the shorter text repeat its last line.
+    RepeatLast,
+    /// The rows end with the shorter text.
+    Truncate,
+}
+
+/// The width of the control characters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ControlPolicy {
+    /// A control character takes no cell.
+    Zero,
+    /// A control character is one cell wide.
+    #[default]
+    One,
+    /// A control character is displayed in caret notation, like `^G`, two cells wide.
+    Escape,
+}
+
+/// The width of the first column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WidthStrategy {
+    /// The width of the longest line of the first text.
+    #[default]
+    Max,
+    /// A fixed width, the longer lines are not filled.
+    Fixed(usize),
+    /// The width of the longest line so far, growing with the rows.
+    Running,
+}
+
+/// The vertical line between the boxed columns.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BorderMode {
+    /// The line is drawn on every row.
+    #[default]
+    Dense,
+    /// The line is drawn on the rows with both cells not empty only.
+    Sparse,
+}
+
+/// The alignment of the lines of the first column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Align {
+    /// The lines are filled up after them.
+    #[default]
+    Left,
+    /// The lines are filled up before them.
+    Right,
+    /// The lines are filled up on both sides, the odd fill after them.
+    Center,
+}
+
+impl Align {
+    /// Returns the fill before a line of the column from the fill of the line.
+    #[inline]
+    fn before(self, fill_len: usize) -> usize {
+        match self {
+            Self::Left => 0,
+            Self::Right => fill_len,
+            Self::Center => fill_len / 2,
+        }
+    }
+}
+
+/// The place of the ellipsis of a truncated text.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TruncateMode {
+    /// The end of the text is replaced by the ellipsis.
+    #[default]
+    End,
+    /// The middle of the text is replaced by the ellipsis, the start and end are kept.
+    Middle,
+}
+
+/// The measure of the text width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WidthMode {
+    /// Every character is one cell wide.
+    Chars,
+    /// Every byte is one cell wide.
+    ///
+    /// - Correct for ASCII and other texts of one byte per cell only.
+    Bytes,
+    /// Grapheme clusters are as wide as a terminal displays them.
+    ///
+    /// - The default since 0.4, the texts were measured in characters before.
+    #[default]
+    Unicode,
+}
+
 /// A structure to store the delimiter character and its repetition value.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct CatToCol {
-    fill: SmallString<[u8; 4]>,
+    fill: Cow<'static, str>,
     repeat: usize,
+    clip: Option<usize>,
+    wrap_width: Option<usize>,
+    prefix: String,
+    suffix: String,
+    empty_cell: Option<String>,
+    empty_left_cell: Option<String>,
+    direction: Direction,
+    zebra: Option<(String, String)>,
+    width_mode: WidthMode,
+    ambiguous_wide: bool,
+    tab_align: Option<usize>,
+    boxed: bool,
+    symmetric_gap: bool,
+    compact_tail: bool,
+    min_row_height: usize,
+    equal_columns: bool,
+    split_cr: bool,
+    header_rule: Option<String>,
+    hanging_indent: usize,
+    min_column_width: usize,
+    fill_cycle: Vec<String>,
+    column_widths: Vec<usize>,
+    break_chars: Vec<char>,
+    line_numbers: bool,
+    leading_tab: Option<usize>,
+    collapse_empty_left: bool,
+    skip_empty_rows: EmptyPolicy,
+    control_width: ControlPolicy,
+    align: Align,
+    indent_width: usize,
+    sanitize_cells: bool,
+    row_separator: Cow<'static, str>,
+    block_terminator: Option<Cow<'static, str>>,
+    width_strategy: WidthStrategy,
+    cell_bg: Option<String>,
+    empty_right_gutter: Option<String>,
+    border_mode: BorderMode,
+    tab_stops: Option<usize>,
+    nbsp_fill: bool,
+    balance: Option<usize>,
+    tail_policy: TailPolicy,
+    detect_ansi: bool,
+    width_overrides: Vec<(char, usize)>,
 }
 
 impl CatToCol {
     /// Create a new CatToCol.
-    pub fn new() -> Self {
+    ///
+    /// - Usable in constants and statics.
+    pub const fn new() -> Self {
         Self {
-            fill: ' '.into(),
+            fill: Cow::Borrowed(" "),
             repeat: 0,
+            clip: None,
+            wrap_width: None,
+            prefix: String::new(),
+            suffix: String::new(),
+            empty_cell: None,
+            empty_left_cell: None,
+            direction: Direction::Ltr,
+            zebra: None,
+            width_mode: WidthMode::Unicode,
+            ambiguous_wide: false,
+            tab_align: None,
+            boxed: false,
+            symmetric_gap: false,
+            compact_tail: false,
+            min_row_height: 1,
+            equal_columns: false,
+            split_cr: false,
+            header_rule: None,
+            hanging_indent: 0,
+            min_column_width: 0,
+            fill_cycle: Vec::new(),
+            column_widths: Vec::new(),
+            break_chars: Vec::new(),
+            line_numbers: false,
+            leading_tab: None,
+            collapse_empty_left: false,
+            skip_empty_rows: EmptyPolicy::Never,
+            control_width: ControlPolicy::One,
+            align: Align::Left,
+            indent_width: 2,
+            sanitize_cells: false,
+            row_separator: Cow::Borrowed("\n"),
+            block_terminator: Some(Cow::Borrowed("\n")),
+            width_strategy: WidthStrategy::Max,
+            cell_bg: None,
+            empty_right_gutter: None,
+            border_mode: BorderMode::Dense,
+            tab_stops: None,
+            nbsp_fill: false,
+            balance: None,
+            tail_policy: TailPolicy::Blank,
+            detect_ansi: false,
+            width_overrides: Vec::new(),
         }
     }
 
     /// Changes the separator character.
     #[inline]
     pub fn fill(mut self, fill: char) -> Self {
-        self.fill = fill.into();
+        self.fill = Cow::Owned(fill.to_string());
+        self
+    }
+
+    /// Returns the separator.
+    #[inline]
+    pub fn get_fill(&self) -> &str {
+        &self.fill
+    }
+
+    /// Returns the repetition value.
+    #[inline]
+    pub fn get_repeat(&self) -> usize {
+        self.repeat
+    }
+
+    /// Changes the separator to a single grapheme cluster.
+    ///
+    /// - For fills that are not one `char`, like a letter with a combining accent.
+    /// - [`CatToCol::validate`] rejects a fill that is not one cell wide.
+    #[inline]
+    pub fn fill_str(mut self, fill: &str) -> Self {
+        self.fill = Cow::Owned(fill.to_string());
+        self
+    }
+
+    /// Changes the separator back to the default space.
+    #[inline]
+    pub fn reset_fill(mut self) -> Self {
+        self.fill = Self::new().fill;
+        self
+    }
+
+    /// Changes the repetition value back to the default zero.
+    #[inline]
+    pub fn reset_repeat(mut self) -> Self {
+        self.repeat = Self::new().repeat;
+        self
+    }
+
+    /// Changes all options back to the defaults.
+    #[inline]
+    pub fn with_defaults(self) -> Self {
+        Self::new()
+    }
+
+    /// Changes the widths of the characters, overriding the width mode.
+    ///
+    /// - For the characters a terminal displays wider or narrower than measured.
+    /// - Overrides a character displayed on its own, not in a grapheme cluster with others.
+    /// - The control characters keep the width of the control policy.
+    #[inline]
+    pub fn width_overrides(mut self, overrides: &[(char, usize)]) -> Self {
+        self.width_overrides = overrides.to_vec();
+        self
+    }
+
+    /// Pads with non-breaking spaces instead of spaces.
+    ///
+    /// - For the output embedded in HTML, where the spaces collapse.
+    /// - The space fill and the spaces before the right cells are replaced.
+    /// - A non-breaking space is one cell wide.
+    #[inline]
+    pub fn nbsp_fill(mut self, nbsp_fill: bool) -> Self {
+        self.nbsp_fill = nbsp_fill;
+        self
+    }
+
+    /// Changes the fill to the characters repeated in turn.
+    ///
+    /// - The character is chosen by the column, the leaders of the rows line up.
+    /// - Without characters the fill is used.
+    #[inline]
+    pub fn fill_cycle(mut self, fill_cycle: &[char]) -> Self {
+        self.fill_cycle = fill_cycle.iter().map(char::to_string).collect();
         self
     }
 
@@ -83,1092 +404,6023 @@ impl CatToCol {
         self
     }
 
-    /// Combining two texts in columns separated by a character repeated N times.
+    /// Wraps the lines of the first text at the given width.
     ///
-    /// - Without the ansi escpe sequences.
+    /// - Lines are broken at spaces where possible.
+    /// - [`CatToCol::combine_cols`] wraps the lines of every text.
     #[inline]
-    pub fn combine_col<'a>(
-        &'a self,
-        str_one: &'a str,
-        str_two: &'a str,
-    ) -> impl Iterator<Item = &str> {
-        let max_line_one = max_line_len(str_one);
-        let iter_one = str_one.lines();
-        let iter_two = str_two.lines();
-        let len_min = min(iter_one.clone().count(), iter_two.clone().count());
-        let txt_iter = iter_one.clone().zip(iter_two.clone());
-
-        txt_iter
-            .flat_map(move |item| {
-                let just_len = max_line_one - max_line_len(item.0);
-                iter::once(item.0)
-                    .chain(iter::repeat(self.fill.as_str()).take(just_len + self.repeat))
-                    .chain(iter::once(item.1))
-                    .chain(iter::once("\n"))
-            })
-            .chain(
-                iter_one
-                    .skip(len_min)
-                    .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
-            )
-            .chain(iter_two.skip(len_min).flat_map(move |line| {
-                iter::repeat(self.fill.as_str())
-                    .take(max_line_one + self.repeat)
-                    .chain(iter::once(line))
-                    .chain(iter::once("\n"))
-            }))
+    pub fn wrap_width(mut self, width: usize) -> Self {
+        self.wrap_width = Some(width);
+        self
     }
 
-    /// Combining two texts in columns separated by a character repeated N times.
+    /// Aligns the second column with tabs instead of the fill.
     ///
-    /// - With the ansi escpe sequences.  
+    /// - The left cell is followed by tabs up to the first tab stop after the widest left cell.
+    /// - Only aligns visually on terminals honoring tab stops of the given width.
+    /// - The fill and its repetition are not used.
     #[inline]
-    pub fn combine_col_esc<'a>(
-        &'a self,
-        str_one: &'a str,
-        str_two: &'a str,
-    ) -> impl Iterator<Item = &str> {
-        let max_line_one = max_line_len_no_esc(str_one);
-        let iter_one = str_one.lines();
-        let iter_two = str_two.lines();
-        let len_min = min(iter_one.clone().count(), iter_two.clone().count());
-        let txt_iter = iter_one.clone().zip(iter_two.clone());
-
-        txt_iter
-            .flat_map(move |item| {
-                let just_len = max_line_one - max_line_len_no_esc(item.0);
-                iter::once(item.0)
-                    .chain(iter::repeat(self.fill.as_str()).take(just_len + self.repeat))
-                    .chain(iter::once(item.1))
-                    .chain(iter::once("\n"))
-            })
-            .chain(
-                iter_one
-                    .skip(len_min)
-                    .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
-            )
-            .chain(iter_two.skip(len_min).flat_map(move |line| {
-                iter::repeat(self.fill.as_str())
-                    .take(max_line_one + self.repeat)
-                    .chain(iter::once(line))
-                    .chain(iter::once("\n"))
-            }))
+    pub fn tab_align(mut self, tab_stop: usize) -> Self {
+        self.tab_align = Some(tab_stop);
+        self
     }
-}
 
-/// Concatenating two texts line by line returns an iterator.
-///
-/// - Empty lines of the first text are concatenated with spaces.
-/// - No lines are ignored.
-/// # Examples
-///
-/// ```
-/// use cattocol::cat_to_col;
-/// let first_txt = "Combine\ntexts\none\nlinewise.\n\n";
-/// let second_txt = "two\ninto\ntext\n";
-/// let text = "Combine two\ntexts into\none text\nlinewise.\n\n";
-/// let concatenated_txt = cat_to_col(&first_txt, &second_txt).collect::<String>();
-///
-/// assert_eq!(concatenated_txt, text);
-///
-/// let first_txt = "Combine\ntexts\none\n";
-/// let second_txt = "two\ninto\ntext\nlinewise.\n\n";
-/// let text = "Combine two\ntexts into\none text\nlinewise.\n\n";
-/// let concatenated_txt = cat_to_col(&first_txt, &second_txt).collect::<String>();
-///
-/// assert_eq!(concatenated_txt, text);
-/// ```
-#[inline]
-pub fn cat_to_col<'a>(str_one: &'a str, str_two: &'a str) -> impl Iterator<Item = &'a str> {
-    let iter_one = str_one.lines();
-    let iter_two = str_two.lines();
-    let len_min = min(iter_one.clone().count(), iter_two.clone().count());
-    let txt_iter = iter_one.clone().zip(iter_two.clone());
+    /// Aligns the second column to the next stop of the interval with the fill.
+    ///
+    /// - The second column begins at a multiple of the interval, counted from the beginning of the row.
+    /// - The stop is at or past the widest left cell and the fill repeated N times.
+    /// - Not for the boxed or equal columns or the tab alignment.
+    #[inline]
+    pub fn tab_stops(mut self, interval: usize) -> Self {
+        self.tab_stops = Some(interval);
+        self
+    }
 
-    txt_iter
-        .flat_map(move |item| {
-            iter::once(item.0)
-                .chain(iter::once(" "))
-                .chain(iter::once(item.1))
-                .chain(iter::once("\n"))
-        })
-        .chain(
-            iter_one
-                .skip(len_min)
-                .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
-        )
-        .chain(
-            iter_two
-                .skip(len_min)
-                .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
-        )
-}
+    /// Repeats the fill before the second column as well as after the first.
+    ///
+    /// - The first column is still padded to its widest line, then followed by the gap.
+    /// - The second column is preceded by the same gap, making the gap twice as wide.
+    #[inline]
+    pub fn symmetric_gap(mut self, symmetric_gap: bool) -> Self {
+        self.symmetric_gap = symmetric_gap;
+        self
+    }
 
-/// Concatenating two texts along the lines of the first text returns an iterator.
-///
-/// - Lines are joined by whitespace.
-/// - If the first text ends, the remaining lines of the second text are ignored.
-/// - No spaces are inserted before or after empty lines.
-/// # Examples
-///
-/// ```
-/// use cattocol::by_lines;
-///
-/// let first_txt = "One green\nrides down";
-/// let second_txt = "brutal tractor\nthe street.";
-/// let concatenated_txt = by_lines(first_txt, second_txt).collect::<String>();
-///
-/// assert_eq!(&concatenated_txt, "One green brutal tractor\nrides down the street.\n");
-///
-/// let first_txt = "One green\nrides down";
-/// let second_txt = "brutal tractor\nthe street.\nThe tractor\nhums and smokes.";
-/// let concatenated_txt = by_lines(first_txt, second_txt).collect::<String>();
-///
-/// assert_eq!(&concatenated_txt, "One green brutal tractor\nrides down the street.\n");
-/// ```
-#[inline]
-pub fn by_lines<'a>(first_str: &'a str, second_str: &'a str) -> impl Iterator<Item = &'a str> + 'a {
-    let first_iter = first_str.lines();
-    let mut second_iter = second_str.lines();
+    /// Leaves the rows after the end of the first text unpadded.
+    ///
+    /// - The remaining lines of the second text start at the beginning of the row.
+    /// - Rows with the placeholder of the first column are padded.
+    /// - Has no effect on the boxed columns.
+    #[inline]
+    pub fn compact_tail(mut self, compact_tail: bool) -> Self {
+        self.compact_tail = compact_tail;
+        self
+    }
 
-    first_iter.flat_map(move |first_line| {
-        let mut space_take = 0;
-        let second_line = if let Some(line) = second_iter.next() {
-            if first_line.is_empty() || line.is_empty() {
-                space_take = 0
-            } else {
-                space_take = 1
-            };
-            line
-        } else {
-            ""
-        }
-        .lines();
-        iter::once(first_line).chain(
-            iter::once(" ")
-                .take(space_take)
-                .chain(second_line)
-                .chain(iter::once("\n")),
-        )
-    })
-}
+    /// Leaves the rows with an empty line of the first text unpadded.
+    ///
+    /// - The line of the second text starts at the beginning of the row.
+    /// - The rows after the end of the first text are padded unless the tail is compact.
+    /// - Has no effect on the boxed columns.
+    #[inline]
+    pub fn collapse_empty_left(mut self, collapse_empty_left: bool) -> Self {
+        self.collapse_empty_left = collapse_empty_left;
+        self
+    }
 
-/// Concatenating two texts by lines parwise returns an iterator.
-///
-/// - Lines are joined by whitespace.
-/// - Unpaired and empty lines are ignored.
-/// # Examples
-///
-/// ```
-/// use cattocol::by_pairs;
-///
-/// let first_txt = "one horsepower\ntwo horsepower\nthree horsepower\nfour horsepower\n";
-/// let second_txt = "per horse\ntwo horses\n";
-/// let concatenated_txt = by_pairs(first_txt, second_txt).collect::<String>();
-///
-/// assert_eq!( &concatenated_txt, "one horsepower per horse\ntwo horsepower two horses\n");
-///
-/// let first_txt = "red apple\ngreen pear\nyellow tomato\npurple eggplant\n";
-/// let second_txt = "";
-/// let concatenated_txt = by_pairs(first_txt, second_txt).collect::<String>();
-///
-/// assert_eq!( &concatenated_txt, "");
-/// ```
-#[inline]
-pub fn by_pairs<'a>(first_str: &'a str, second_str: &'a str) -> impl Iterator<Item = &'a str> + 'a {
-    let first_iter = first_str.lines();
-    let mut second_iter = second_str.lines();
+    /// Changes the rows omitted from [`CatToCol::combine_col`] by their empty cells.
+    ///
+    /// - A cell with the placeholder is not empty.
+    /// - The columns are as wide as with the omitted rows.
+    #[inline]
+    pub fn skip_empty_rows(mut self, policy: EmptyPolicy) -> Self {
+        self.skip_empty_rows = policy;
+        self
+    }
 
-    first_iter.flat_map(move |first_line| {
-        let mut takes = 0;
-        let second_line = if let Some(line) = second_iter.next() {
-            takes = usize::MAX;
-            line
-        } else {
-            ""
-        };
+    /// Detects the ansi escpe sequences in the texts of [`CatToCol::combine_col`].
+    ///
+    /// - Texts with an escape character are combined as by [`CatToCol::combine_col_esc`].
+    /// - Other texts are combined without the ansi escpe sequences.
+    #[inline]
+    pub fn detect_ansi(mut self, detect_ansi: bool) -> Self {
+        self.detect_ansi = detect_ansi;
+        self
+    }
 
-        if first_line.is_empty() || second_line.is_empty() {
-            takes = 0;
-        };
+    /// Changes the rows of [`CatToCol::combine_col`] after the end of the shorter text.
+    ///
+    /// - The repeated last line is a line as any other, it is wrapped and measured.
+    /// - An empty text has no last line to repeat.
+    #[inline]
+    pub fn tail_policy(mut self, tail_policy: TailPolicy) -> Self {
+        self.tail_policy = tail_policy;
+        self
+    }
 
-        iter::once(first_line)
-            .chain(
-                iter::once(" ")
-                    .chain(second_line.lines())
-                    .chain(iter::once("\n")),
-            )
-            .take(takes)
-    })
-}
+    /// Fills both columns up to the width of the wider one.
+    ///
+    /// - Every row is filled up, the combined texts form a rectangle.
+    /// - Takes precedence over the tab alignment and the compact tail.
+    #[inline]
+    pub fn equal_columns(mut self, equal_columns: bool) -> Self {
+        self.equal_columns = equal_columns;
+        self
+    }
 
-/// Concatenating three texts along the lines of the first text returns an iterator.
-///
-/// - Lines are joined by whitespace.
-/// - If the first text ends, the remaining lines of the second text are ignored.
-/// - No spaces are inserted before or after empty lines.
-/// # Examples
-///
-/// ```
-/// use cattocol::by_three_lines;
-///
-/// let first_txt = "One season\nDecembre,\nIt's cold.\n";
-/// let second_txt = "a year\nJanuary,\n";
-/// let third_txt = "is winter.\nFebruary.\n";
-/// let text = "One season a year is winter.\nDecembre, January, February.\nIt's cold.\n";
-/// let concatenated_txt = by_three_lines(first_txt, second_txt, third_txt).collect::<String>();
-///
-/// assert_eq!( &concatenated_txt, text);
-///
-/// ```
-#[inline]
-pub fn by_three_lines<'a>(
-    first_str: &'a str,
-    second_str: &'a str,
-    third_str: &'a str,
-) -> impl Iterator<Item = &'a str> {
-    let first_iter = first_str.lines();
-    let mut second_iter = second_str.lines();
-    let mut third_iter = third_str.lines();
+    /// Draws a box with box-drawing characters around the columns.
+    ///
+    /// - The columns are separated by a vertical line after the fill.
+    /// - In the ansi mode the frame is not styled.
+    /// - Takes precedence over the tab alignment.
+    #[inline]
+    pub fn boxed(mut self, boxed: bool) -> Self {
+        self.boxed = boxed;
+        self
+    }
 
-    first_iter.flat_map(move |first_line| {
-        let mut first_space_take = 0;
-        let mut second_space_take = 0;
-        let first_line_notempty = !first_line.is_empty();
-        let mut second_line_notempty = false;
-        let mut second_line = "";
-        let mut third_line = "";
+    /// Changes where the vertical line between the boxed columns is drawn.
+    ///
+    /// - A sparse line is replaced by a space on the rows with an empty cell.
+    /// - The outer frame is drawn on every row.
+    #[inline]
+    pub fn border_mode(mut self, border_mode: BorderMode) -> Self {
+        self.border_mode = border_mode;
+        self
+    }
 
-        if let Some(line) = second_iter.next() {
-            second_line_notempty = !line.is_empty();
-            if first_line_notempty && second_line_notempty {
-                first_space_take = 1;
-            };
-            second_line = line;
+    /// Changes the least number of rows a line takes.
+    ///
+    /// - Lines with fewer pieces are followed by rows of empty cells.
+    /// - A line of a column wrapped into more rows makes the other columns of the row as high.
+    #[inline]
+    pub fn min_row_height(mut self, min_row_height: usize) -> Self {
+        self.min_row_height = min_row_height;
+        self
+    }
+
+    /// Breaks the lines of the texts at a lone carriage return as well.
+    ///
+    /// - For texts with the classic Mac line endings.
+    #[inline]
+    pub fn split_cr(mut self, split_cr: bool) -> Self {
+        self.split_cr = split_cr;
+        self
+    }
+
+    /// Changes the character of the rule below the titles.
+    ///
+    /// - Without a character no rule is drawn.
+    #[inline]
+    pub fn header_rule(mut self, rule: Option<char>) -> Self {
+        self.header_rule = rule.map(String::from);
+        self
+    }
+
+    /// Indents the wrapped rows of a line after the first by N spaces.
+    ///
+    /// - The indent is counted into the wrap width.
+    #[inline]
+    pub fn hanging_indent(mut self, hanging_indent: usize) -> Self {
+        self.hanging_indent = hanging_indent;
+        self
+    }
+
+    /// Changes the indent of a level of [`CatToCol::combine_col_tree`].
+    ///
+    /// - Two spaces by default.
+    #[inline]
+    pub fn indent_width(mut self, indent_width: usize) -> Self {
+        self.indent_width = indent_width;
+        self
+    }
+
+    /// Changes how the width of the first column of [`CatToCol::combine_col`] is taken.
+    ///
+    /// - [`WidthStrategy::Fixed`] and [`WidthStrategy::Running`] measure the lines as the rows are taken.
+    /// - The boxed, equal and numbered columns take all the lines first.
+    /// - The boxed and equal columns are as wide as the longest line.
+    #[inline]
+    pub fn width_strategy(mut self, width_strategy: WidthStrategy) -> Self {
+        self.width_strategy = width_strategy;
+        self
+    }
+
+    /// Changes the least width of the first column.
+    ///
+    /// - An empty or narrower first column is filled up to the width.
+    #[inline]
+    pub fn min_column_width(mut self, min_column_width: usize) -> Self {
+        self.min_column_width = min_column_width;
+        self
+    }
+
+    /// Breaks the lines of the texts at the characters as well.
+    ///
+    /// - For texts with form feeds or vertical tabs between sections.
+    /// - The break characters are not part of the rows.
+    #[inline]
+    pub fn break_chars(mut self, break_chars: &[char]) -> Self {
+        self.break_chars = break_chars.to_vec();
+        self
+    }
+
+    /// Numbers the rows of [`CatToCol::combine_col`] in a leading column.
+    ///
+    /// - The numbers are right-aligned to the width of the number of rows.
+    /// - Two spaces follow the number, the rows of the tail are numbered too.
+    /// - The rows of a [`ColumnBuilder`] are not numbered, their number is not known ahead.
+    #[inline]
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Converts the leading tabs of the lines to spaces up to the next tab stop of N.
+    ///
+    /// - Applied to the columns of [`CatToCol::combine_col`] before they are measured.
+    /// - The tabs after the first other character are left as they are.
+    #[inline]
+    pub fn normalize_leading_whitespace(mut self, tab_stop: usize) -> Self {
+        self.leading_tab = Some(tab_stop);
+        self
+    }
+
+    /// Changes the text between the rows, a newline by default.
+    ///
+    /// - The rows of the column builder are always separated by a newline.
+    #[inline]
+    pub fn row_separator(mut self, separator: &str) -> Self {
+        self.row_separator = Cow::Owned(separator.to_string());
+        self
+    }
+
+    /// Changes the text after the last row, a newline by default.
+    ///
+    /// - Without a terminator the last row is not terminated.
+    #[inline]
+    pub fn block_terminator(mut self, terminator: Option<&str>) -> Self {
+        self.block_terminator = terminator.map(|terminator| Cow::Owned(terminator.to_string()));
+        self
+    }
+
+    /// Changes the text inserted at the beginning of each row.
+    #[inline]
+    pub fn row_prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Changes the text inserted at the end of each row.
+    #[inline]
+    pub fn row_suffix(mut self, suffix: &str) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Changes the text placed into empty cells of the second column.
+    #[inline]
+    pub fn empty_cell(mut self, placeholder: &str) -> Self {
+        self.empty_cell = Some(placeholder.to_string());
+        self
+    }
+
+    /// Changes the gutter of the rows with an empty cell of the second column.
+    ///
+    /// - An empty gutter is omitted, the row ends with the left cell.
+    /// - `None` restores the full gutter of the fill.
+    /// - Not for the boxed or equal columns, they keep the full gutter.
+    #[inline]
+    pub fn gutter_if_empty_right(mut self, gutter: Option<&str>) -> Self {
+        self.empty_right_gutter = gutter.map(str::to_string);
+        self
+    }
+
+    /// Changes the text placed into empty cells of the first column.
+    #[inline]
+    pub fn empty_left_cell(mut self, placeholder: &str) -> Self {
+        self.empty_left_cell = Some(placeholder.to_string());
+        self
+    }
+
+    /// Changes the alignment of the lines of the first column.
+    ///
+    /// - The lines with the ansi escpe sequences are aligned by their visible width.
+    /// - The rows of a compact tail or an empty collapsed left cell are not aligned.
+    #[inline]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Changes the order of the columns.
+    ///
+    /// - With [`Direction::Rtl`] the first text is placed into the right column.
+    /// - The column options apply to the columns as placed.
+    /// - Only the columns are swapped, the characters are not reordered.
+    #[inline]
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Changes the measure of the text width.
+    ///
+    /// - [`WidthMode::Unicode`] aligns wide characters and combining marks as displayed.
+    /// - [`WidthMode::Unicode`] by default since 0.4, [`WidthMode::Chars`] measures as 0.3 did.
+    #[inline]
+    pub fn width_mode(mut self, width_mode: WidthMode) -> Self {
+        self.width_mode = width_mode;
+        self
+    }
+
+    /// Changes the width of the control characters other than the tab.
+    ///
+    /// - The C0 control characters and the delete character.
+    /// - [`ControlPolicy::Escape`] displays them in caret notation in [`CatToCol::combine_col`].
+    /// - Outside the escape sequences of [`CatToCol::combine_col_esc`] they are measured alike.
+    #[inline]
+    pub fn control_width(mut self, control_width: ControlPolicy) -> Self {
+        self.control_width = control_width;
+        self
+    }
+
+    /// Replaces the control characters within the cells by spaces.
+    ///
+    /// - The C0 control characters other than the tab, like a carriage return, and the delete character.
+    /// - The escape sequences of [`CatToCol::combine_col_esc`] are kept, a stray escape is replaced.
+    /// - A stray escape is one without a terminated CSI or OSC sequence, the spaces are one cell wide.
+    /// - Takes precedence over the control width.
+    #[inline]
+    pub fn sanitize_cells(mut self, sanitize_cells: bool) -> Self {
+        self.sanitize_cells = sanitize_cells;
+        self
+    }
+
+    /// Changes the width of the East Asian Ambiguous characters.
+    ///
+    /// - Two cells wide if true, as on terminals configured for CJK.
+    /// - Applies to [`WidthMode::Unicode`] only.
+    #[inline]
+    pub fn ambiguous_wide(mut self, ambiguous_wide: bool) -> Self {
+        self.ambiguous_wide = ambiguous_wide;
+        self
+    }
+
+    /// Changes the styles of the even and the odd rows.
+    ///
+    /// - Only with the ansi escpe sequences.
+    /// - Each row starts with its style and ends with a reset.
+    #[inline]
+    pub fn zebra(mut self, styles: Option<(&str, &str)>) -> Self {
+        self.zebra = styles.map(|(even, odd)| (even.to_string(), odd.to_string()));
+        self
+    }
+
+    /// Changes the background of the left cells, the fill after the text included.
+    ///
+    /// - Only with the ansi escpe sequences.
+    /// - The background is set again after the text and ends with a reset.
+    #[inline]
+    pub fn cell_bg(mut self, bg: &str) -> Self {
+        self.cell_bg = Some(bg.to_string());
+        self
+    }
+
+    /// Clips each row to a number of cells.
+    ///
+    /// - Escape sequences are zero width and are never dropped.
+    #[inline]
+    pub fn clip_width(mut self, width: usize) -> Self {
+        self.clip = Some(width);
+        self
+    }
+
+    /// Clips each row to the width of the terminal.
+    ///
+    /// - The width is detected once, when the option is set.
+    /// - Without a terminal the rows are not clipped.
+    #[cfg(feature = "terminal")]
+    #[inline]
+    pub fn clip_to_terminal(mut self, clip: bool) -> Self {
+        self.clip = if clip {
+            terminal_size::terminal_size().map(|(terminal_size::Width(width), _)| width as usize)
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Caps each column to its fraction of the width of the terminal.
+    ///
+    /// - The width is detected once, when the option is set.
+    /// - Without a terminal the columns are not capped.
+    /// - See [`CatToCol::column_fractions_of`].
+    #[cfg(feature = "terminal")]
+    #[inline]
+    pub fn column_fractions(self, fractions: &[f32]) -> Self {
+        match terminal_size::terminal_size() {
+            Some((terminal_size::Width(width), _)) => {
+                self.column_fractions_of(width as usize, fractions)
+            }
+            None => self,
         }
+    }
 
-        if let Some(line) = third_iter.next() {
-            if (first_line_notempty || second_line_notempty) && !line.is_empty() {
-                second_space_take = 1;
-            };
-            third_line = line;
+    /// Caps each column to its fraction of the width.
+    ///
+    /// - The fractions are normalized to sum up to one.
+    /// - The fill after a column is counted into its fraction.
+    /// - The first columns are wrapped, the last column of [`CatToCol::combine_col`] is truncated.
+    pub fn column_fractions_of(mut self, width: usize, fractions: &[f32]) -> Self {
+        let sum = fractions
+            .iter()
+            .map(|fraction| fraction.max(0.0))
+            .sum::<f32>();
+        self.column_widths = match sum > 0.0 {
+            true => fractions
+                .iter()
+                .map(|fraction| (width as f32 * fraction.max(0.0) / sum) as usize)
+                .collect(),
+            false => Vec::new(),
+        };
+        self
+    }
+
+    /// Balances the columns of [`CatToCol::combine_cols`] and [`CatToCol::combine_col`] to the total width.
+    ///
+    /// - The width without the fill and the prefix and suffix is shared by the longest lines.
+    /// - Every column is at least one cell wide, the rest of the sharing goes to the last.
+    /// - Every column is wrapped at its share, the second of [`CatToCol::combine_col`] too.
+    #[inline]
+    pub fn balance_to(mut self, total: usize) -> Self {
+        self.balance = Some(total);
+        self
+    }
+
+    /// Checks the configuration for impossible option combinations.
+    ///
+    /// - A zero wrap width is combined as a width of one.
+    /// - A zero tab stop is combined as a tab stop of one.
+    pub fn validate(&self) -> Result<(), CatToColError> {
+        if self.fill.chars().any(char::is_control) {
+            return Err(CatToColError::ControlFill);
+        }
+        if self.fill.graphemes(true).count() != 1 || self.fill.width() != 1 {
+            return Err(CatToColError::FillWidth);
+        }
+        if self.wrap_width == Some(0) {
+            return Err(CatToColError::ZeroWrapWidth);
         }
+        if [self.tab_align, self.leading_tab, self.tab_stops].contains(&Some(0)) {
+            return Err(CatToColError::ZeroTabStop);
+        }
+        Ok(())
+    }
 
-        iter::once(first_line)
-            .chain(
-                iter::once(" ")
-                    .take(first_space_take)
-                    .chain(second_line.lines()),
-            )
-            .chain(
-                iter::once(" ")
-                    .take(second_space_take)
-                    .chain(third_line.lines()),
-            )
-            .chain(iter::once("\n"))
-    })
-}
+    #[inline]
+    fn measure(&self) -> Measure<'_> {
+        Measure {
+            mode: self.width_mode,
+            ambiguous_wide: self.ambiguous_wide,
+            control: match self.sanitize_cells {
+                true => ControlPolicy::One,
+                false => self.control_width,
+            },
+            overrides: &self.width_overrides,
+            sanitize: self.sanitize_cells,
+        }
+    }
+
+    /// Combining two texts in columns separated by a character repeated N times.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - A cell wider than its column, like a wide character beyond the wrap width, is not filled.
+    /// - The lines are measured by the width mode, see [`CatToCol::width_mode`].
+    /// - Texts with an escape character are combined as with them if detected, see [`CatToCol::detect_ansi`].
+    #[inline]
+    pub fn combine_col<'a>(&'a self, str_one: &'a str, str_two: &'a str) -> CombineColIter<'a> {
+        let esc = self.detect_ansi && (str_one.contains('\x1b') || str_two.contains('\x1b'));
+        CombineColIter {
+            iter: self.combine_fragments(str_one, str_two, esc),
+        }
+    }
+
+    /// Combining two texts in columns separated by a character repeated N times.
+    ///
+    /// - With the ansi escpe sequences.  
+    /// - Wrapped lines reopen the styles still active at the wrap point.
+    /// - Hyperlinks (OSC 8) are measured by their visible label only.
+    /// - The text without the escape sequences is measured by the width mode.
+    #[inline]
+    pub fn combine_col_esc<'a>(&'a self, str_one: &'a str, str_two: &'a str) -> CombineColIter<'a> {
+        CombineColIter {
+            iter: self.combine_fragments(str_one, str_two, true),
+        }
+    }
+
+    /// Combining two texts in columns with the ansi escape sequences, if the options are valid.
+    ///
+    /// - The error of [`CatToCol::validate`] for an impossible configuration.
+    /// - Any text is combined, a truncated or unterminated escape sequence is not an error.
+    #[inline]
+    pub fn try_combine_col_esc<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> Result<CombineColIter<'a>, CatToColError> {
+        self.validate()?;
+        Ok(self.combine_col_esc(str_one, str_two))
+    }
+
+    /// Joining the lines of two texts with the fill repeated N times, without aligning them.
+    ///
+    /// - The lines are not measured, the fill is not repeated up to the longest line.
+    /// - The remaining lines of the longer text follow on their own rows, as of [`cat_to_col`].
+    #[inline]
+    pub fn join_col<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        let (str_one, str_two) = match self.direction {
+            Direction::Ltr => (str_one, str_two),
+            Direction::Rtl => (str_two, str_one),
+        };
+        let len_min = min(self.lines(str_one).count(), self.lines(str_two).count());
+        let pairs = self
+            .lines(str_one)
+            .zip(self.lines(str_two))
+            .map(|(line_one, line_two)| (line_one, Some(line_two)));
+        let tails = self
+            .lines(str_one)
+            .skip(len_min)
+            .chain(self.lines(str_two).skip(len_min))
+            .map(|line| (line, None));
+
+        let iter = pairs.chain(tails).flat_map(move |(line_one, line_two)| {
+            iter::once(self.prefix.as_str())
+                .chain(iter::once(line_one))
+                .chain(line_two.into_iter().flat_map(move |line_two| {
+                    iter::repeat_n(self.get_fill(), self.repeat).chain(iter::once(line_two))
+                }))
+                .chain(iter::once(self.suffix.as_str()))
+                .chain(iter::once("\n"))
+        });
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Returns the number of rows of [`CatToCol::combine_col`].
+    ///
+    /// - Counts the rows of the wrapped lines and the frame of the boxed columns.
+    #[inline]
+    pub fn row_count(&self, str_one: &str, str_two: &str) -> usize {
+        let full = self.boxed || self.equal_columns;
+        let rows = self.layout(str_one, str_two, false, full).1.count();
+        match self.boxed && rows > 0 {
+            true => rows + 2,
+            false => rows,
+        }
+    }
+
+    /// Returns true if every row of two texts combined in columns fits into the width.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The rows as of [`CatToCol::row_iter`], the fill and the prefix and suffix included.
+    #[inline]
+    pub fn fits(&self, str_one: &str, str_two: &str, max_width: usize) -> bool {
+        self.row_iter(str_one, str_two)
+            .all(|row| row.width() <= max_width)
+    }
+
+    /// Combining two texts in columns returns the text with its width and number of rows.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The width and rows as of [`CatToCol::row_iter`] and [`CatToCol::row_count`].
+    pub fn combine_col_measured(&self, str_one: &str, str_two: &str) -> Combined {
+        let width = self
+            .row_iter(str_one, str_two)
+            .map(|row| row.width())
+            .max()
+            .unwrap_or(0);
+        Combined {
+            text: self.combine_col(str_one, str_two).collect(),
+            width,
+            rows: self.row_count(str_one, str_two),
+        }
+    }
+
+    /// Combining the pairs of lines in columns returns the text.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The first lines of the pairs are the first column, the second lines the second column.
+    /// - Every pair begins a row, a pair with more lines takes as many rows as its longer element.
+    pub fn combine_pairs(&self, pairs: &[(&str, &str)]) -> String {
+        let mut lines_one = Vec::new();
+        let mut lines_two = Vec::new();
+        for &(one, two) in pairs {
+            let len = max(self.lines(one).count(), self.lines(two).count()).max(1);
+            lines_one.extend(self.lines(one).chain(iter::repeat("")).take(len));
+            lines_two.extend(self.lines(two).chain(iter::repeat("")).take(len));
+        }
+        self.combine_col(&lines_one.join("\n"), &lines_two.join("\n"))
+            .collect()
+    }
+
+    /// Combining two texts in columns returns the text with the ranges of its rows.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The rows are borrowed from the text, there is one allocation for all of them.
+    pub fn combine_col_buffer(&self, str_one: &str, str_two: &str) -> CombinedBuffer {
+        let mut buffer = CombinedBuffer::default();
+        let mut start = 0;
+        for fragment in self.combine_fragments(str_one, str_two, false) {
+            let text = fragment.text();
+            if fragment.is_row_end() {
+                buffer.rows.push(start..buffer.text.len());
+                start = buffer.text.len() + text.len();
+            }
+            buffer.text.push_str(text);
+        }
+        if start < buffer.text.len() {
+            buffer.rows.push(start..buffer.text.len());
+        }
+        buffer
+    }
+
+    /// Combining two owned texts in columns returns an iterator over the rows.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every row ends with the row separator, the last one with the block terminator.
+    pub fn combine_owned(self, str_one: String, str_two: String) -> impl Iterator<Item = String> {
+        let mut rows = Vec::new();
+        let mut row = String::new();
+        for fragment in self.combine_fragments(&str_one, &str_two, false) {
+            row.push_str(fragment.text());
+            if fragment.is_row_end() {
+                rows.push(mem::take(&mut row));
+            }
+        }
+        if !row.is_empty() {
+            rows.push(row);
+        }
+        rows.into_iter()
+    }
+
+    /// Combining two texts in columns into the buffer.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The buffer is cleared first, its capacity is reused.
+    #[inline]
+    pub fn combine_col_into(&self, str_one: &str, str_two: &str, buf: &mut String) {
+        buf.clear();
+        for fragment in self.combine(str_one, str_two, false) {
+            buf.push_str(fragment);
+        }
+    }
+
+    /// Combining two texts in columns returns an iterator over the rows in the range.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The columns are as wide as for all rows.
+    #[inline]
+    pub fn combine_col_range<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        range: Range<usize>,
+    ) -> impl Iterator<Item = &'a str> {
+        self.combine_fragments(str_one, str_two, false)
+            .scan(0, move |index, fragment| {
+                if *index >= range.end {
+                    return None;
+                }
+                let in_range = range.contains(index);
+                if fragment.is_row_end() {
+                    *index += 1;
+                }
+                Some(Some(fragment.text()).filter(|_| in_range))
+            })
+            .flatten()
+    }
+
+    /// Combining two texts in columns with the fill runs joined into one item.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The lines of the texts are borrowed, only the runs of fill are owned.
+    #[inline]
+    pub fn combine_col_cow<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = Cow<'a, str>> {
+        self.fill_runs(str_one, str_two)
+            .map(|(fragment, _)| fragment)
+    }
+
+    /// Returns the text fragments with the runs of fill joined and their kinds.
+    fn fill_runs<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = (Cow<'a, str>, Run)> {
+        let mut iter = self.combine_fragments(str_one, str_two, false).peekable();
+        let is_fill = |fragment: &Fragment| matches!(fragment, Fragment::Fill(_));
+
+        iter::from_fn(move || {
+            let fragment = iter.next()?;
+            let fill = match fragment {
+                Fragment::Text(text) => return Some((Cow::Borrowed(text), Run::Text)),
+                Fragment::RowEnd(text) => return Some((Cow::Borrowed(text), Run::RowEnd)),
+                Fragment::Fill(fill) => fill,
+            };
+            if iter.peek().is_none_or(|next| !is_fill(next)) {
+                return Some((Cow::Borrowed(fill), Run::Fill(1)));
+            }
+            let mut run = fill.to_owned();
+            let mut len = 1;
+            while let Some(next) = iter.next_if(is_fill) {
+                run.push_str(next.text());
+                len += 1;
+            }
+            Some((Cow::Owned(run), Run::Fill(len)))
+        })
+    }
+
+    /// Combining two texts in columns with the fill of every row returned by the closure.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The closure is called with the index of the row, once per row with any fill.
+    #[inline]
+    pub fn combine_col_fill_fn<'a, F>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        mut fill_fn: F,
+    ) -> impl Iterator<Item = Cow<'a, str>>
+    where
+        F: FnMut(usize) -> char + 'a,
+    {
+        let mut index = 0;
+        let mut row_fill = None;
+
+        self.fill_runs(str_one, str_two)
+            .map(move |(fragment, run)| match run {
+                Run::RowEnd => {
+                    index += 1;
+                    row_fill = None;
+                    fragment
+                }
+                Run::Text => fragment,
+                Run::Fill(len) => {
+                    let chr = *row_fill.get_or_insert_with(|| fill_fn(index));
+                    Cow::Owned(iter::repeat_n(chr, len).collect())
+                }
+            })
+    }
+
+    /// Combining two texts in columns of fixed widths.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Longer lines are truncated, shorter lines are filled up to the width.
+    #[inline]
+    pub fn combine_col_fixed<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        width_one: usize,
+        width_two: usize,
+    ) -> impl Iterator<Item = &'a str> {
+        let (str_one, str_two, width_one, width_two) = match self.direction {
+            Direction::Ltr => (str_one, str_two, width_one, width_two),
+            Direction::Rtl => (str_two, str_one, width_two, width_one),
+        };
+        let measure = self.measure();
+        let len_max = max(self.lines(str_one).count(), self.lines(str_two).count());
+        let iter_one = self
+            .lines(str_one)
+            .map(Some)
+            .chain(iter::repeat(None))
+            .map(|line| empty_or(line, self.empty_left_cell.as_deref()).unwrap_or_default());
+        let iter_two = self
+            .lines(str_two)
+            .map(Some)
+            .chain(iter::repeat(None))
+            .map(|line| empty_or(line, self.empty_cell.as_deref()).unwrap_or_default());
+
+        let iter = iter_one
+            .zip(iter_two)
+            .take(len_max)
+            .flat_map(move |(line_one, line_two)| {
+                let (end_one, len_one) = measure.cut_index(line_one, width_one, false);
+                let (end_two, len_two) = measure.cut_index(line_two, width_two, false);
+                iter::once(self.prefix.as_str())
+                    .chain(iter::once(&line_one[..end_one]))
+                    .chain(iter::repeat_n(
+                        self.get_fill(),
+                        width_one.saturating_sub(len_one) + self.repeat,
+                    ))
+                    .chain(iter::once(&line_two[..end_two]))
+                    .chain(iter::repeat_n(
+                        self.get_fill(),
+                        width_two.saturating_sub(len_two),
+                    ))
+                    .chain(iter::once(self.suffix.as_str()))
+                    .chain(iter::once("\n"))
+            });
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Combining two texts in columns with the tab separated fields of the first text in columns.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every field is filled up to the widest field of its column, followed by the fill.
+    /// - The second text is placed after the columns of the fields.
+    pub fn combine_tsv<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        let measure = self.measure();
+        let len_max = max(self.lines(str_one).count(), self.lines(str_two).count());
+        let mut widths = Vec::new();
+        for line in self.lines(str_one) {
+            for (index, field) in line.split('\t').enumerate() {
+                if index == widths.len() {
+                    widths.push(0);
+                }
+                widths[index] = max(widths[index], measure.str_width(field));
+            }
+        }
+        let iter_one = self.lines(str_one).map(Some).chain(iter::repeat(None));
+        let iter_two = self.lines(str_two).map(Some).chain(iter::repeat(None));
+
+        let iter = iter_one
+            .zip(iter_two)
+            .take(len_max)
+            .flat_map(move |(line_one, line_two)| {
+                let line_one = line_one.unwrap_or_default();
+                let len = match line_two {
+                    Some(_) => widths.len(),
+                    None => line_one.split('\t').count(),
+                };
+                let mut fields = line_one.split('\t');
+                let mut row = vec![self.prefix.as_str()];
+                let mut col = 0;
+                for (index, &width) in widths.iter().enumerate().take(len) {
+                    let field = fields.next().unwrap_or_default();
+                    row.push(field);
+                    if index + 1 < len || line_two.is_some() {
+                        let field_width = measure.str_width(field);
+                        let just_len = width - field_width + self.repeat;
+                        row.extend(self.fill_run(col + field_width, just_len));
+                        col += field_width + just_len;
+                    }
+                }
+                row.extend(line_two);
+                row.push(self.suffix.as_str());
+                row.push("\n");
+                row
+            });
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Combining two texts in columns with the lines of the first text indented by their level.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - A line is indented by its level from the closure times the indent width.
+    /// - The second column is aligned after the deepest indented line.
+    #[inline]
+    pub fn combine_col_tree<'a, F>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        level_fn: F,
+    ) -> impl Iterator<Item = &'a str>
+    where
+        F: Fn(&str) -> usize,
+    {
+        let measure = self.measure();
+        let len_max = max(self.lines(str_one).count(), self.lines(str_two).count());
+        let cells_one = self
+            .lines(str_one)
+            .map(|line| (level_fn(line) * self.indent_width, line))
+            .collect::<Vec<_>>();
+        let max_line_one = cells_one
+            .iter()
+            .map(|&(indent, line)| indent + measure.str_width(line))
+            .max()
+            .unwrap_or(0)
+            .max(self.min_column_width);
+        let iter_one = cells_one.into_iter().map(Some).chain(iter::repeat(None));
+        let iter_two = self.lines(str_two).map(Some).chain(iter::repeat(None));
+
+        let iter = iter_one
+            .zip(iter_two)
+            .take(len_max)
+            .flat_map(move |(cell_one, line_two)| {
+                let (indent, line_one) = cell_one.unwrap_or_default();
+                let left_width = indent + measure.str_width(line_one);
+                let just_len = match line_two {
+                    Some(_) => max_line_one.saturating_sub(left_width) + self.repeat,
+                    None => 0,
+                };
+                iter::once(self.prefix.as_str())
+                    .chain(iter::repeat_n(" ", indent))
+                    .chain(iter::once(line_one))
+                    .chain(self.fill_run(left_width, just_len))
+                    .chain(line_two)
+                    .chain(iter::once(self.suffix.as_str()))
+                    .chain(iter::once("\n"))
+            });
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Combining two texts in columns with the lines of the second column wrapped to the width.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The lines of the first column are not wrapped, the left cell of a wrapped row is empty.
+    #[inline]
+    pub fn combine_col_wrap_right<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        width: usize,
+    ) -> impl Iterator<Item = &'a str> {
+        let (str_one, str_two) = match self.direction {
+            Direction::Ltr => (str_one, str_two),
+            Direction::Rtl => (str_two, str_one),
+        };
+        let measure = self.measure();
+        let len_max = max(self.lines(str_one).count(), self.lines(str_two).count());
+        let iter_one = self
+            .lines(str_one)
+            .map(Some)
+            .chain(iter::repeat(None))
+            .map(|line| empty_or(line, self.empty_left_cell.as_deref()).unwrap_or_default());
+        let max_line_one = iter_one
+            .clone()
+            .take(len_max)
+            .map(|line| measure.line_len_no_esc(line))
+            .max()
+            .unwrap_or(0)
+            .max(self.min_column_width);
+        let iter_two = self
+            .lines(str_two)
+            .map(Some)
+            .chain(iter::repeat(None))
+            .map(|line| empty_or(line, self.empty_cell.as_deref()));
+
+        let iter = iter_one
+            .zip(iter_two)
+            .take(len_max)
+            .flat_map(move |(line_one, line_two)| {
+                let pieces = match line_two {
+                    Some(line) => measure.wrap_line(line, width, self.hanging_indent, false),
+                    None => vec![""],
+                };
+                let wrapped = line_two.is_some();
+                pieces
+                    .into_iter()
+                    .enumerate()
+                    .flat_map(move |(index, piece)| {
+                        let left = if index == 0 { line_one } else { "" };
+                        let left_len = measure.line_len_no_esc(left);
+                        let fill_len = match wrapped {
+                            true => max_line_one.saturating_sub(left_len) + self.repeat,
+                            false => 0,
+                        };
+                        iter::once(self.prefix.as_str())
+                            .chain(iter::once(left))
+                            .chain(self.fill_run(left_len, fill_len))
+                            .chain(iter::repeat_n(" ", self.indent(index)))
+                            .chain(iter::once(piece))
+                            .chain(iter::once(self.suffix.as_str()))
+                            .chain(iter::once("\n"))
+                    })
+            });
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Writing two texts combined in columns to the writer.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The text fragments are collected into a buffer before writing.
+    #[inline]
+    pub fn write_combine_col<W: Write>(
+        &self,
+        writer: &mut W,
+        str_one: &str,
+        str_two: &str,
+    ) -> io::Result<()> {
+        write_batched(writer, self.combine(str_one, str_two, false))
+    }
+
+    /// Writing two texts combined in columns to the writer.
+    ///
+    /// - With the ansi escpe sequences.
+    /// - The text fragments are collected into a buffer before writing.
+    #[inline]
+    pub fn write_combine_col_esc<W: Write>(
+        &self,
+        writer: &mut W,
+        str_one: &str,
+        str_two: &str,
+    ) -> io::Result<()> {
+        write_batched(writer, self.combine(str_one, str_two, true))
+    }
+
+    /// Printing two texts combined in columns to the standard output.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The standard output is locked once, the rows of other threads are not interleaved.
+    #[inline]
+    pub fn print(&self, str_one: &str, str_two: &str) -> io::Result<()> {
+        self.write_combine_col(&mut io::stdout().lock(), str_one, str_two)
+    }
+
+    /// Printing two texts combined in columns to the standard output.
+    ///
+    /// - With the ansi escpe sequences.
+    /// - The standard output is locked once, the rows of other threads are not interleaved.
+    #[inline]
+    pub fn print_esc(&self, str_one: &str, str_two: &str) -> io::Result<()> {
+        self.write_combine_col_esc(&mut io::stdout().lock(), str_one, str_two)
+    }
+
+    /// Printing two texts combined in columns to the standard error.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The standard error is locked once, the rows of other threads are not interleaved.
+    #[inline]
+    pub fn eprint(&self, str_one: &str, str_two: &str) -> io::Result<()> {
+        self.write_combine_col(&mut io::stderr().lock(), str_one, str_two)
+    }
+
+    /// Writing two texts combined in columns to the writer through the encoder.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every text fragment, the fill and the newlines included, is encoded before writing.
+    /// - The encoded bytes are collected into a buffer before writing.
+    pub fn write_combine_col_encoded<W: Write, E: Fn(&str) -> Vec<u8>>(
+        &self,
+        writer: &mut W,
+        str_one: &str,
+        str_two: &str,
+        encode: E,
+    ) -> io::Result<()> {
+        let mut buf = Vec::with_capacity(WRITE_BUF_LEN);
+        for fragment in self.combine(str_one, str_two, false) {
+            buf.extend(encode(fragment));
+            if buf.len() >= WRITE_BUF_LEN {
+                writer.write_all(&buf)?;
+                buf.clear();
+            }
+        }
+        if !buf.is_empty() {
+            writer.write_all(&buf)?;
+        }
+        Ok(())
+    }
+
+    /// Combining any number of texts in columns separated by a character repeated N times.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - An empty slice returns an empty iterator.
+    /// - A single text returns its lines, each terminated by a newline.
+    /// - The last column of a row is not filled up.
+    /// - Every pair of adjacent columns is separated by the fill repeated N times.
+    /// - Every column is wrapped, the lines of a row take as many rows as the highest.
+    #[inline]
+    pub fn combine_cols<'a>(&'a self, texts: &[&'a str]) -> impl Iterator<Item = &'a str> {
+        Clip::new(self.cols(texts, self.plan(texts), Vec::new()), self, false)
+    }
+
+    /// Combining any number of texts in columns, each filled with its own character.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Combines as [`CatToCol::combine_cols`], the fill after a column is its character.
+    /// - The columns after the end of the characters are filled with the fill.
+    pub fn combine_cols_fill(&self, texts: &[&str], fills: &[char]) -> String {
+        let fills = fills.iter().map(char::to_string).collect::<Vec<_>>();
+        let fills = fills.iter().map(String::as_str).collect();
+        Clip::new(self.cols(texts, self.plan(texts), fills), self, false).collect()
+    }
+
+    /// Combining a fixed number of texts in columns separated by a character repeated N times.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Combines as [`CatToCol::combine_cols`], the widths of the columns are kept in an array.
+    #[inline]
+    pub fn combine_cols_n<'a, const N: usize>(
+        &'a self,
+        texts: [&'a str; N],
+    ) -> impl Iterator<Item = &'a str> {
+        let balanced = self.balanced(&texts, false);
+        let widths: [usize; N] = std::array::from_fn(|column| match &balanced {
+            Some(widths) => widths[column],
+            None => self.plan_column(column, texts[column]),
+        });
+        Clip::new(self.cols(&texts, widths, Vec::new()), self, false)
+    }
+
+    /// Combining two blocks of text side by side into a rectangle.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every line of both blocks is filled up to the width of its block.
+    /// - The missing lines of the shorter block are filled up as well.
+    #[inline]
+    pub fn combine_blocks<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        self.combine_grid(self.grid(str_one, str_two, false, true))
+    }
+
+    /// Combining two blocks of text side by side, rotated so the rows become the columns.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The grid of [`CatToCol::to_grid`] read column by column.
+    /// - A row with wide characters becomes a column as wide as them, the other cells are filled up.
+    pub fn combine_rotated(&self, str_one: &str, str_two: &str) -> String {
+        let measure = self.measure();
+        let chr_width = |chr: char| match chr {
+            '\0' => 0,
+            chr => measure.str_width(chr.encode_utf8(&mut [0; 4])),
+        };
+        let fill = self.get_fill();
+        let grid = self.to_grid(str_one, str_two);
+        let widths = grid
+            .iter()
+            .map(|row| row.iter().map(|&chr| chr_width(chr)).max().unwrap_or(0))
+            .collect::<Vec<_>>();
+        let height = grid.first().map_or(0, Vec::len);
+        let mut rotated = String::new();
+        for column in 0..height {
+            for (row, &width) in grid.iter().zip(&widths) {
+                let chr = row[column];
+                if chr != '\0' {
+                    rotated.push(chr);
+                }
+                rotated.extend(iter::repeat_n(fill, width - chr_width(chr)));
+            }
+            rotated.push('\n');
+        }
+        rotated
+    }
+
+    /// Combining three texts in columns separated by a character repeated N times.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every column is as wide as its longest line.
+    /// - The rows after the end of a text are filled up to the columns after it.
+    #[inline]
+    pub fn combine_col3<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        str_three: &'a str,
+    ) -> impl Iterator<Item = &'a str> {
+        self.combine_cols(&[str_one, str_two, str_three])
+    }
+
+    /// Combining any number of texts in columns below a row of titles.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The columns are as wide as the widest of their lines and title.
+    /// - The titles are followed by the header rule if there is one.
+    #[inline]
+    pub fn with_titles<'a>(
+        &'a self,
+        titles: &[&'a str],
+        texts: &[&'a str],
+    ) -> impl Iterator<Item = &'a str> {
+        let mut widths = self.plan(texts);
+        let title_widths = self.plan(titles);
+        widths.resize(max(widths.len(), title_widths.len()), 0);
+        for (width, title_width) in widths.iter_mut().zip(title_widths) {
+            *width = max(*width, title_width);
+        }
+        let rule = self.header_rule.as_deref().map(|rule| {
+            let last = widths.len().saturating_sub(1);
+            let mut row = vec![self.prefix.as_str()];
+            for (index, &width) in widths.iter().enumerate() {
+                row.extend(iter::repeat_n(rule, width));
+                if index < last {
+                    row.extend(iter::repeat_n(self.get_fill(), self.repeat));
+                }
+            }
+            row.push(self.suffix.as_str());
+            row.push("\n");
+            row
+        });
+
+        let iter = self
+            .cols(titles, widths.clone(), Vec::new())
+            .chain(rule.into_iter().flatten())
+            .chain(self.cols(texts, widths, Vec::new()));
+
+        Clip::new(iter, self, false)
+    }
+
+    /// Returns the text fragments of the texts in columns of the widths.
+    ///
+    /// - The columns without a fill of their own are filled with the fill.
+    fn cols<'a, W>(
+        &'a self,
+        texts: &[&'a str],
+        widths: W,
+        fills: Vec<&'a str>,
+    ) -> impl Iterator<Item = &'a str>
+    where
+        W: AsRef<[usize]> + 'a,
+    {
+        let len_max = texts
+            .iter()
+            .map(|text| self.lines(text).count())
+            .max()
+            .unwrap_or(0);
+        let mut iters = texts
+            .iter()
+            .map(|text| self.lines(text))
+            .collect::<Vec<_>>();
+
+        (0..len_max).flat_map(move |_| {
+            let cells = iters
+                .iter_mut()
+                .enumerate()
+                .map(|(column, iter)| {
+                    iter.next().map(|line| match self.balance {
+                        Some(_) => self.measure().wrap_line(
+                            line,
+                            widths.as_ref()[column],
+                            self.hanging_indent,
+                            false,
+                        ),
+                        None => self.wrap(line, column, false),
+                    })
+                })
+                .collect::<Vec<_>>();
+            let last = cells.iter().rposition(Option::is_some).unwrap_or(0);
+            let height = cells.iter().flatten().map(Vec::len).max().unwrap_or(1);
+            let mut row = Vec::new();
+            for piece_index in 0..max(height, self.min_row_height) {
+                row.push(self.prefix.as_str());
+                for (index, cell) in cells.iter().enumerate().take(last + 1) {
+                    let line = cell
+                        .as_ref()
+                        .and_then(|cell| cell.get(piece_index))
+                        .copied();
+                    let indent = line.map_or(0, |_| self.indent(piece_index));
+                    let line = line.unwrap_or_default();
+                    row.extend(iter::repeat_n(" ", indent));
+                    row.push(line);
+                    if index < last {
+                        let line_len = indent + self.measure().str_width(line);
+                        let just_len = widths.as_ref()[index].saturating_sub(line_len);
+                        let fill = fills.get(index).copied().unwrap_or(self.get_fill());
+                        row.extend(iter::repeat_n(fill, just_len + self.repeat));
+                    }
+                }
+                row.push(self.suffix.as_str());
+                row.push("\n");
+            }
+            row
+        })
+    }
+
+    /// Returns the width of every line of a text.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Measured by the width mode, the lines are not wrapped.
+    #[inline]
+    pub fn line_widths<'a>(&'a self, text: &'a str) -> impl Iterator<Item = usize> + 'a {
+        let measure = self.measure();
+        self.lines(text).map(move |line| measure.str_width(line))
+    }
+
+    /// Returns the width of every column of [`CatToCol::combine_cols`].
+    ///
+    /// - The widths of the wrapped lines, without the fill.
+    #[inline]
+    pub fn plan(&self, texts: &[&str]) -> Vec<usize> {
+        if let Some(widths) = self.balanced(texts, false) {
+            return widths;
+        }
+        texts
+            .iter()
+            .enumerate()
+            .map(|(column, text)| self.plan_column(column, text))
+            .collect()
+    }
+
+    /// Returns the widths of the columns balanced to the total width.
+    fn balanced(&self, texts: &[&str], esc: bool) -> Option<Vec<usize>> {
+        let total = self.balance?;
+        let measure = self.measure();
+        let mut widths = texts
+            .iter()
+            .map(|text| {
+                self.lines(text)
+                    .map(|line| measure.line_len(line, esc))
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+        let gutter = measure.str_width(&self.prefix)
+            + measure.str_width(&self.suffix)
+            + self.repeat * widths.len().saturating_sub(1);
+        let share = total.saturating_sub(gutter).max(widths.len());
+        let sum = widths.iter().sum::<usize>().max(1);
+        for width in &mut widths {
+            *width = max(1, *width * share / sum);
+        }
+        let rest = share.saturating_sub(widths.iter().sum());
+        if let Some(last) = widths.last_mut() {
+            *last += rest;
+        }
+        Some(widths)
+    }
+
+    /// Returns the width of a column of the wrapped lines of the text.
+    #[inline]
+    fn plan_column(&self, column: usize, text: &str) -> usize {
+        self.lines(text)
+            .flat_map(|line| self.wrap(line, column, false).into_iter().enumerate())
+            .map(|(index, piece)| self.indent(index) + self.measure().str_width(piece))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the width of the widest row of [`CatToCol::combine_cols`].
+    ///
+    /// - The columns with the fill between them and the prefix and suffix.
+    /// - The clip width if the row is wider.
+    pub fn combined_width(&self, texts: &[&str]) -> usize {
+        let widths = self.plan(texts);
+        if widths.is_empty() {
+            return 0;
+        }
+        let width = self.measure().str_width(&self.prefix)
+            + widths.iter().sum::<usize>()
+            + self.repeat * (widths.len() - 1)
+            + self.measure().str_width(&self.suffix);
+        self.clip.map_or(width, |clip| min(width, clip))
+    }
+
+    /// Filling the lines of a text up to its longest line returns an iterator.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The padded first column of [`CatToCol::combine_col`] without the second.
+    #[inline]
+    pub fn left_column<'a>(&'a self, str_one: &'a str) -> impl Iterator<Item = &'a str> {
+        let cells_one = self
+            .lines(str_one)
+            .map(|line| self.cell(line, false))
+            .collect::<Vec<_>>();
+        let max_line_one = cells_one
+            .iter()
+            .flatten()
+            .map(|piece| piece.width)
+            .max()
+            .unwrap_or(0)
+            .max(self.min_column_width);
+
+        cells_one.into_iter().flatten().flat_map(move |piece| {
+            let before = self.align.before(max_line_one - piece.width);
+            iter::repeat_n(self.get_fill(), before)
+                .chain(iter::repeat_n(" ", piece.indent))
+                .chain(iter::once(piece.text))
+                .chain(iter::repeat_n(
+                    self.get_fill(),
+                    max_line_one - piece.width - before,
+                ))
+                .chain(iter::once("\n"))
+        })
+    }
+
+    /// Combining two texts into rows returns an iterator.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - A row displays as the row of [`CatToCol::combine_col`] without the newline.
+    /// - The frame of the boxed columns is not included.
+    #[inline]
+    pub fn row_iter<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = Row<'a>> {
+        let (grid, rows) = self.grid(str_one, str_two, false, false);
+
+        rows.into_iter().enumerate().map(move |(index, cells)| {
+            let left = cells.left.as_ref().map_or("", |piece| piece.text);
+            let right = cells.right;
+            let tail = cells.left.is_none() && right.is_some();
+            let text = Clip::new(self.row(grid, index, cells), self, false).collect::<String>();
+            Row {
+                left,
+                right,
+                width: self.measure().str_width(&text),
+                tail,
+                text,
+            }
+        })
+    }
+
+    /// Returns the column where the second column of every row begins.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The rows of [`CatToCol::combine_col`], counted from the beginning of the row.
+    /// - The right cell of a compact row begins after the prefix.
+    #[inline]
+    pub fn right_offsets<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+    ) -> impl Iterator<Item = usize> + 'a {
+        let (grid, rows) = self.grid(str_one, str_two, false, false);
+        let frames = 2 * usize::from(self.boxed);
+        let measure = self.measure();
+
+        rows.into_iter().map(move |row| {
+            let (before, tabs, just_len, lead_len) = self.gutter(grid, &row);
+            let gutter_len = self
+                .empty_gutter(grid, &row)
+                .map_or(0, |gutter| measure.line_len(gutter, false));
+            let left_end = grid.prefix_len + before + row.left_width() + just_len + gutter_len;
+            let left_end = match self.tab_align {
+                Some(tab_stop) if tabs > 0 => (left_end / tab_stop.max(1) + tabs) * tab_stop.max(1),
+                _ => left_end,
+            };
+            left_end + frames + lead_len
+        })
+    }
+
+    /// Combining two texts into rows of padded left cells and right cells.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The left cells are filled up to the width of the first column.
+    pub fn rows(&self, str_one: &str, str_two: &str) -> Vec<(String, String)> {
+        let rows = self.layout(str_one, str_two, false, self.equal_columns).1;
+
+        rows.map(|row| {
+            let just_len = row.column.saturating_sub(row.left_width());
+            let right = " ".repeat(row.right_indent) + row.right.unwrap_or_default();
+            let left = row
+                .into_left()
+                .chain(iter::repeat_n(self.get_fill(), just_len))
+                .collect();
+            (left, right)
+        })
+        .collect()
+    }
+
+    /// Combining two texts into a JSON array of rows.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every row is an object of the `left` and the `right` cell.
+    /// - The left cells are filled up to the width of the first column if padded.
+    #[cfg(feature = "json")]
+    pub fn rows_json(&self, str_one: &str, str_two: &str, padded: bool) -> String {
+        let rows = match padded {
+            true => self.rows(str_one, str_two),
+            false => self
+                .layout(str_one, str_two, false, self.equal_columns)
+                .1
+                .map(|row| {
+                    let left = row.left.map_or("", |piece| piece.text);
+                    (left.to_string(), row.right.unwrap_or_default().to_string())
+                })
+                .collect(),
+        };
+        let rows = rows
+            .into_iter()
+            .map(|(left, right)| serde_json::json!({ "left": left, "right": right }))
+            .collect();
+
+        serde_json::Value::Array(rows).to_string()
+    }
+
+    /// Aligning the lines of a text on the first delimiter of each returns an iterator.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The parts before the delimiter are filled up to the widest of them.
+    /// - Lines without the delimiter are filled up as well if `pad_missing` is true.
+    /// - Each line is terminated by a newline.
+    #[inline]
+    pub fn align_on_str<'a>(
+        &'a self,
+        text: &'a str,
+        delim: &'a str,
+        pad_missing: bool,
+    ) -> impl Iterator<Item = String> + 'a {
+        let max_key = self
+            .lines(text)
+            .filter_map(|line| line.split_once(delim))
+            .map(|(key, _)| self.measure().str_width(key))
+            .max()
+            .unwrap_or(0);
+
+        self.lines(text).map(move |line| {
+            let (key, rest) = match line.split_once(delim) {
+                Some((key, value)) => (key, Some(value)),
+                None => (line, None),
+            };
+            let just_len = match rest.is_some() || pad_missing {
+                true => max_key.saturating_sub(self.measure().str_width(key)),
+                false => 0,
+            };
+            iter::once(key)
+                .chain(iter::repeat_n(self.get_fill(), just_len))
+                .chain(rest.into_iter().flat_map(|value| [delim, value]))
+                .chain(iter::once("\n"))
+                .collect()
+        })
+    }
+
+    /// Combining two texts into a rectangular grid of cells.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - Every row is filled up to the widest row.
+    /// - A wide character is followed by a `'\0'` for each further cell it takes.
+    /// - Zero width characters like combining marks are left out.
+    pub fn to_grid(&self, str_one: &str, str_two: &str) -> Vec<Vec<char>> {
+        let measure = self.measure();
+        let fill = self.get_fill().chars().next().unwrap_or(' ');
+        let text = self.combine_col(str_one, str_two).collect::<String>();
+        let mut grid = text
+            .lines()
+            .map(|line| {
+                let mut row = Vec::new();
+                for chr in line.chars() {
+                    let width = measure.str_width(chr.encode_utf8(&mut [0; 4]));
+                    if width > 0 {
+                        row.push(chr);
+                        row.extend(iter::repeat_n('\0', width - 1));
+                    }
+                }
+                row
+            })
+            .collect::<Vec<_>>();
+        let width = grid.iter().map(Vec::len).max().unwrap_or(0);
+        for row in &mut grid {
+            row.resize(width, fill);
+        }
+        grid
+    }
+
+    /// Combining two texts into rows shaped by the closure returns an iterator.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The closure receives the padded left cell and the right cell of a row.
+    /// - Each row is terminated by a newline.
+    #[inline]
+    pub fn combine_col_map<F>(
+        &self,
+        str_one: &str,
+        str_two: &str,
+        mut f: F,
+    ) -> impl Iterator<Item = String>
+    where
+        F: FnMut(&str, &str) -> String,
+    {
+        self.rows(str_one, str_two)
+            .into_iter()
+            .map(move |(left, right)| {
+                let mut row = f(&left, &right);
+                row.push('\n');
+                row
+            })
+    }
+
+    /// Combining a text in columns with the cells of the second column returned by the closure.
+    ///
+    /// - Without the ansi escpe sequences.
+    /// - The closure is called with the index and the line of the first text, once per item.
+    /// - Every item is the rows of a line of the first text, the wrapped rows without a right cell.
+    pub fn combine_col_with_fn<'a, F>(
+        &'a self,
+        str_one: &'a str,
+        mut f: F,
+    ) -> impl Iterator<Item = String> + 'a
+    where
+        F: FnMut(usize, &str) -> String + 'a,
+    {
+        let cells = self
+            .lines(str_one)
+            .map(|line| (line, self.cell(line, false)))
+            .collect::<Vec<_>>();
+        let max_line_one = cells
+            .iter()
+            .flat_map(|(_, cell)| cell)
+            .map(|piece| piece.width)
+            .max()
+            .unwrap_or(0)
+            .max(self.min_column_width);
+
+        cells
+            .into_iter()
+            .enumerate()
+            .map(move |(index, (line, cell))| {
+                let right = f(index, line);
+                let mut rows = String::new();
+                for (piece_index, piece) in cell.into_iter().enumerate() {
+                    rows.push_str(&self.prefix);
+                    rows.extend(iter::repeat_n(" ", piece.indent));
+                    rows.push_str(piece.text);
+                    if piece_index == 0 {
+                        let just_len = max_line_one - piece.width + self.repeat;
+                        rows.extend(self.fill_run(piece.width, just_len));
+                        rows.push_str(&right);
+                    }
+                    rows.push_str(&self.suffix);
+                    rows.push('\n');
+                }
+                rows
+            })
+    }
+
+    fn combine<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        esc: bool,
+    ) -> impl Iterator<Item = &'a str> {
+        self.combine_fragments(str_one, str_two, esc)
+            .map(Fragment::text)
+    }
+
+    /// Returns the fragments of the combined texts with the row ends tagged.
+    #[inline]
+    fn combine_fragments<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        esc: bool,
+    ) -> Clip<'a, GridFragments<'a, GridRows<'a>>> {
+        self.grid_fragments(self.grid_iter(str_one, str_two, esc, false))
+    }
+
+    /// Returns the text fragments of the rows of the grid with the newlines and borders.
+    #[inline]
+    fn combine_grid<'a, R>(&'a self, grid_rows: (Grid, R)) -> impl Iterator<Item = &'a str>
+    where
+        R: IntoIterator<Item = Cells<'a>>,
+        R::IntoIter: 'a,
+    {
+        self.grid_fragments(grid_rows).map(Fragment::text)
+    }
+
+    /// Returns the fragments of the rows of the grid with the newlines and borders.
+    fn grid_fragments<'a, R>(
+        &'a self,
+        (grid, rows): (Grid, R),
+    ) -> Clip<'a, GridFragments<'a, R::IntoIter>>
+    where
+        R: IntoIterator<Item = Cells<'a>>,
+    {
+        let mut rows = rows.into_iter();
+        let next = rows.next();
+        let mut fragments = GridFragments {
+            cattocol: self,
+            grid,
+            rows,
+            next,
+            index: 0,
+            borders: self.boxed,
+            buf: Vec::new(),
+            pos: 0,
+        };
+        fragments.borders &= fragments.next.is_some();
+        if fragments.borders {
+            fragments.push_border(["┌", "┬", "┐"]);
+        }
+
+        Clip::new(fragments, self, grid.esc)
+    }
+
+    /// Returns the widths of the columns and the cells of the rows of the combined texts.
+    ///
+    /// - Both columns are filled up to their widths on every row if the texts are blocks.
+    #[inline]
+    fn grid<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        esc: bool,
+        blocks: bool,
+    ) -> (Grid, Vec<Cells<'a>>) {
+        let (grid, rows) = self.grid_iter(str_one, str_two, esc, blocks);
+        (grid, rows.collect())
+    }
+
+    /// Returns the widths of the columns and an iterator over the cells of the rows.
+    ///
+    /// - The rows are taken as they are combined unless the widths depend on all of them.
+    fn grid_iter<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        esc: bool,
+        blocks: bool,
+    ) -> (Grid, GridRows<'a>) {
+        let measure = self.measure();
+        let full = self.boxed || self.equal_columns || blocks;
+        let (max_line_one, rows) = self.layout(str_one, str_two, esc, full);
+        let lead = self.repeat * usize::from(self.symmetric_gap);
+        if !full && !self.line_numbers {
+            let grid = Grid {
+                esc,
+                full,
+                number_width: 0,
+                prefix_len: measure.line_len(&self.prefix, esc),
+                max_line_two: 0,
+                width_one: max_line_one + self.repeat,
+                width_two: lead,
+                lead,
+            };
+            return (grid, Either::Right(rows));
+        }
+        let mut rows = rows.collect::<Vec<_>>();
+        let max_line_two = match full {
+            true => rows
+                .iter()
+                .filter_map(|row| Some(row.right_indent + measure.line_len(row.right?, esc)))
+                .max()
+                .unwrap_or(0),
+            false => 0,
+        };
+        let (max_line_one, max_line_two) = match self.equal_columns {
+            true => (
+                max(max_line_one, max_line_two),
+                max(max_line_one, max_line_two),
+            ),
+            false => (max_line_one, max_line_two),
+        };
+        if self.equal_columns {
+            rows.iter_mut().for_each(|row| row.column = max_line_one);
+        }
+        let number_width = match self.line_numbers {
+            true => rows
+                .len()
+                .checked_ilog10()
+                .map_or(1, |log| log as usize + 1),
+            false => 0,
+        };
+        let grid = Grid {
+            esc,
+            full,
+            number_width,
+            prefix_len: number_len(number_width) + measure.line_len(&self.prefix, esc),
+            max_line_two,
+            width_one: max_line_one + self.repeat,
+            width_two: max_line_two + lead,
+            lead,
+        };
+
+        (grid, Either::Left(rows.into_iter()))
+    }
+
+    /// Returns the text fragments of a row without the newline.
+    #[inline]
+    fn row<'a>(
+        &'a self,
+        grid: Grid,
+        index: usize,
+        row: Cells<'a>,
+    ) -> impl Iterator<Item = &'a str> {
+        self.row_fragments(grid, index, row).map(Fragment::text)
+    }
+
+    /// Returns the fragments of a row without the newline.
+    fn row_fragments<'a>(
+        &'a self,
+        grid: Grid,
+        index: usize,
+        row: Cells<'a>,
+    ) -> impl Iterator<Item = Fragment<'a>> {
+        let measure = self.measure();
+        let esc = grid.esc;
+        let frame = self.boxed.then_some(if esc { "\x1b[0m│" } else { "│" });
+        let right = row.right;
+        let right_indent = row.right_indent;
+        let right_len = match grid.full {
+            true => grid
+                .max_line_two
+                .saturating_sub(right_indent + right.map_or(0, |line| measure.line_len(line, esc))),
+            false => 0,
+        };
+        let (before, tabs, just_len, lead_len) = self.gutter(grid, &row);
+        let empty_gutter = self.empty_gutter(grid, &row);
+        let left_width = before + row.left_width();
+        let style = self.zebra.as_ref().filter(|_| esc).map(|(even, odd)| {
+            if index.is_multiple_of(2) {
+                even.as_str()
+            } else {
+                odd.as_str()
+            }
+        });
+        let (outer_style, inner_style) = match self.boxed {
+            true => (None, style),
+            false => (style, None),
+        };
+        let right_start = left_width + just_len + lead_len + grid.max_line_two - right_len;
+        let sparse = self.border_mode == BorderMode::Sparse
+            && (row.left_width() == 0 || right.is_none_or(str::is_empty));
+        let border = frame.map(|bar| match (sparse, esc) {
+            (false, _) => bar,
+            (true, false) => " ",
+            (true, true) => "\x1b[0m ",
+        });
+        let cell_bg = self.cell_bg.as_deref().filter(|_| esc);
+        let head = row_number(index + 1, grid.number_width)
+            .chain(outer_style)
+            .chain(iter::once(self.prefix.as_str()))
+            .chain(frame.map(|_| "│"))
+            .chain(inner_style)
+            .chain(cell_bg);
+        let left = row
+            .into_left()
+            .flat_map(move |text| self.render(text, esc))
+            .chain(cell_bg)
+            .chain(iter::repeat_n("\t", tabs));
+        let gutter = empty_gutter
+            .into_iter()
+            .chain(cell_bg.map(|_| SGR_RESET))
+            .chain(cell_bg.and(outer_style))
+            .chain(border)
+            .chain(inner_style);
+        let right = iter::repeat_n(self.space(), right_indent).chain(
+            right
+                .into_iter()
+                .flat_map(move |text| self.render(text, esc)),
+        );
+        let tail = frame
+            .into_iter()
+            .chain(iter::once(self.suffix.as_str()))
+            .chain(outer_style.map(|_| SGR_RESET));
+
+        head.map(Fragment::Text)
+            .chain(self.fill_run(0, before).map(Fragment::Fill))
+            .chain(left.map(Fragment::Text))
+            .chain(self.fill_run(left_width, just_len).map(Fragment::Fill))
+            .chain(gutter.map(Fragment::Text))
+            .chain(
+                self.fill_run(left_width + just_len, lead_len)
+                    .map(Fragment::Fill),
+            )
+            .chain(right.map(Fragment::Text))
+            .chain(self.fill_run(right_start, right_len).map(Fragment::Fill))
+            .chain(tail.map(Fragment::Text))
+    }
+
+    /// Returns the fill before, the tabs and fill after the left cell and the fill before the right.
+    fn gutter(&self, grid: Grid, row: &Cells) -> (usize, usize, usize, usize) {
+        let left_width = row.left_width();
+        let compact = !grid.full
+            && match &row.left {
+                Some(_) => self.collapse_empty_left && left_width == 0,
+                None => self.compact_tail,
+            };
+        let before = match compact {
+            true => 0,
+            false => self.align.before(row.column.saturating_sub(left_width)),
+        };
+        let left_width = before + left_width;
+        let (tabs, just_len) = match (row.right.filter(|_| !compact), self.tab_align) {
+            _ if grid.full => (0, grid.width_one.saturating_sub(left_width)),
+            (None, _) => (0, 0),
+            (Some(_), None) => {
+                let end = max(row.column, left_width) + self.repeat;
+                let end = match self.tab_stops {
+                    Some(interval) => {
+                        let start = grid.prefix_len + end + grid.lead;
+                        start.next_multiple_of(interval.max(1)) - grid.prefix_len - grid.lead
+                    }
+                    None => end,
+                };
+                (0, end - left_width)
+            }
+            (Some(_), Some(tab_stop)) => {
+                let tab_stop = tab_stop.max(1);
+                let left_end = grid.prefix_len + left_width;
+                let target = (grid.prefix_len + row.column) / tab_stop + 1;
+                (target.saturating_sub(left_end / tab_stop), 0)
+            }
+        };
+        let lead_len = match row.right.is_some() && !compact || grid.full {
+            true => grid.lead,
+            false => 0,
+        };
+        match self.empty_gutter(grid, row) {
+            Some(_) => (before, 0, 0, 0),
+            None => (before, tabs, just_len, lead_len),
+        }
+    }
+
+    /// Returns the gutter replacing the fill of a row with an empty right cell.
+    fn empty_gutter(&self, grid: Grid, row: &Cells) -> Option<&str> {
+        self.empty_right_gutter
+            .as_deref()
+            .filter(|_| !grid.full && row.right == Some(""))
+    }
+
+    /// Returns the text fragments of a text with the control characters in caret notation.
+    ///
+    /// - The text as it is unless the control characters are escaped or sanitized.
+    /// - The sanitized control characters are replaced by spaces, an escape without a complete sequence too.
+    fn render<'a>(&self, text: &'a str, esc: bool) -> impl Iterator<Item = &'a str> {
+        let sanitize = self.sanitize_cells;
+        let escape = sanitize || self.control_width == ControlPolicy::Escape;
+        let mut rest = text;
+        let mut caret = None;
+
+        iter::from_fn(move || {
+            if let Some(letter) = caret.take() {
+                return Some(letter);
+            }
+            if rest.is_empty() {
+                return None;
+            }
+            let mut end = match escape {
+                true => 0,
+                false => rest.len(),
+            };
+            while let Some(chr) = rest[end..].chars().next() {
+                match chr {
+                    '\x1b' if esc && (!sanitize || is_complete_esc(&rest[end..])) => {
+                        end += esc_len(&rest[end..])
+                    }
+                    chr if is_control(chr) => break,
+                    chr => end += chr.len_utf8(),
+                }
+            }
+            if end == 0 && sanitize {
+                rest = &rest[1..];
+                return Some(" ");
+            }
+            if end == 0 {
+                let index = min(usize::from(rest.as_bytes()[0]), 32);
+                caret = Some(&CARETS[index..index + 1]);
+                rest = &rest[1..];
+                return Some("^");
+            }
+            let (run, tail) = rest.split_at(end);
+            rest = tail;
+            Some(run)
+        })
+    }
+
+    /// Returns the fill of the columns from the start column on.
+    #[inline]
+    fn fill_run(&self, start: usize, len: usize) -> impl Iterator<Item = &str> {
+        (start..start + len).map(move |col| match self.fill_cycle.len() {
+            0 if self.nbsp_fill && self.get_fill() == " " => NBSP,
+            0 => self.get_fill(),
+            cycle_len => self.fill_cycle[col % cycle_len].as_str(),
+        })
+    }
+
+    /// Returns the space of the padding.
+    #[inline]
+    fn space(&self) -> &'static str {
+        match self.nbsp_fill {
+            true => NBSP,
+            false => " ",
+        }
+    }
+
+    /// Returns a horizontal line of the box with the given corners.
+    fn border<'a>(
+        &'a self,
+        [left, middle, right]: [&'a str; 3],
+        width_one: usize,
+        width_two: usize,
+    ) -> impl Iterator<Item = &'a str> {
+        iter::once(self.prefix.as_str())
+            .chain(iter::once(left))
+            .chain(iter::repeat_n("─", width_one))
+            .chain(iter::once(middle))
+            .chain(iter::repeat_n("─", width_two))
+            .chain(iter::once(right))
+            .chain(iter::once(self.suffix.as_str()))
+    }
+
+    /// Returns the width of the first column and the rows of the combined texts.
+    ///
+    /// - The lines are measured as the rows are taken if the width of the first column is not the longest line.
+    fn layout<'a>(
+        &'a self,
+        str_one: &'a str,
+        str_two: &'a str,
+        esc: bool,
+        full: bool,
+    ) -> (usize, Rows<'a>) {
+        let (str_one, str_two) = match self.direction {
+            Direction::Ltr => (str_one, str_two),
+            Direction::Rtl => (str_two, str_one),
+        };
+        let tail = |text| match self.tail_policy {
+            TailPolicy::RepeatLast => self.lines(text).last(),
+            _ => None,
+        };
+        let widths = self.balanced(&[str_one, str_two], esc);
+        let lines = LinePairs {
+            cattocol: self,
+            esc,
+            lines_one: self.lines(str_one),
+            lines_two: self.lines(str_two),
+            tail_one: tail(str_one),
+            tail_two: tail(str_two),
+            width_one: widths.as_ref().map(|widths| widths[0]),
+        };
+        let (max_line_one, lines) = match self.width_strategy {
+            WidthStrategy::Fixed(width) if !full => (width, Either::Right(lines)),
+            WidthStrategy::Running if !full => (self.min_column_width, Either::Right(lines)),
+            _ => {
+                let lines = lines.collect::<Vec<_>>();
+                (self.max_left(&lines), Either::Left(lines.into_iter()))
+            }
+        };
+        let rows = Rows {
+            cattocol: self,
+            lines,
+            max_line_one,
+            cells: Vec::new().into_iter(),
+            running: (self.width_strategy == WidthStrategy::Running)
+                .then_some(self.min_column_width),
+            esc,
+            width_two: widths.map(|widths| widths[1]),
+        };
+
+        (max_line_one, rows)
+    }
+
+    /// Returns the width of the widest left cell, at least the least width of the first column.
+    fn max_left(&self, lines: &[(Option<Vec<Piece>>, Option<&str>)]) -> usize {
+        lines
+            .iter()
+            .filter_map(|(cell_one, _)| cell_one.as_ref())
+            .flatten()
+            .map(|piece| piece.width)
+            .max()
+            .unwrap_or(0)
+            .max(self.min_column_width)
+    }
+
+    /// Returns the cells of the rows of a line of each text.
+    fn row_cells<'a>(
+        &self,
+        cell_one: Option<Vec<Piece<'a>>>,
+        line_two: Option<&'a str>,
+        column: usize,
+        width_two: Option<usize>,
+        esc: bool,
+    ) -> Vec<Cells<'a>> {
+        let mut lefts = match cell_one {
+            Some(cell) => cell.into_iter().map(Some).collect::<Vec<_>>(),
+            None => vec![None],
+        };
+        while lefts.len() < self.min_row_height {
+            lefts.push(None);
+        }
+        let (right_indent, line_two) = match line_two {
+            Some(line) => {
+                let (indent, line) = self.leading(line);
+                (indent, Some(line))
+            }
+            None => (0, None),
+        };
+        let rights = match (line_two, width_two) {
+            (Some(line), Some(width)) => {
+                self.measure()
+                    .wrap_line(line, width.saturating_sub(right_indent), 0, esc)
+            }
+            (line, _) => line.into_iter().collect(),
+        };
+        lefts.resize_with(max(lefts.len(), rights.len()), || None);
+        lefts
+            .into_iter()
+            .enumerate()
+            .map(|(index, left)| Cells {
+                left,
+                right: rights.get(index).copied(),
+                right_indent: right_indent * usize::from(index < rights.len()),
+                column,
+            })
+            .collect()
+    }
+
+    /// Returns the lines of a text.
+    #[inline]
+    fn lines<'a>(&self, text: &'a str) -> TextLines<'a> {
+        if !self.split_cr && self.break_chars.is_empty() {
+            return TextLines::Lines(text.lines());
+        }
+        let mut breaks = vec!['\n'];
+        if self.split_cr {
+            breaks.push('\r');
+        }
+        breaks.extend(&self.break_chars);
+        TextLines::Breaks(text, breaks)
+    }
+
+    /// Returns the width of a column from the fractions, without the fill after it.
+    #[inline]
+    fn column_width(&self, column: usize) -> Option<usize> {
+        let width = *self.column_widths.get(column)?;
+        match column + 1 < self.column_widths.len() {
+            true => Some(width.saturating_sub(self.repeat)),
+            false => Some(width),
+        }
+    }
+
+    /// Splits a line into the texts of its rows at the wrap width.
+    #[inline]
+    fn wrap<'a>(&self, line: &'a str, column: usize, esc: bool) -> Vec<&'a str> {
+        match self.column_width(column).or(self.wrap_width) {
+            Some(width) => self
+                .measure()
+                .wrap_line(line, width, self.hanging_indent, esc),
+            None => vec![line],
+        }
+    }
+
+    /// Returns the indent of a piece of a wrapped line.
+    #[inline]
+    fn indent(&self, piece_index: usize) -> usize {
+        match piece_index {
+            0 => 0,
+            _ => self.hanging_indent,
+        }
+    }
+
+    /// Returns the width of the leading whitespace of a line and the rest of the line.
+    ///
+    /// - Nothing is leading without the tab stop of the leading whitespace.
+    #[inline]
+    fn leading<'a>(&self, line: &'a str) -> (usize, &'a str) {
+        let Some(tab_stop) = self.leading_tab else {
+            return (0, line);
+        };
+        let tab_stop = tab_stop.max(1);
+        let rest = line.trim_start_matches([' ', '\t']);
+        let width = line[..line.len() - rest.len()]
+            .chars()
+            .fold(0, |col, chr| match chr {
+                '\t' => (col / tab_stop + 1) * tab_stop,
+                _ => col + 1,
+            });
+        (width, rest)
+    }
+
+    /// Splits a line of the first text into the pieces of its rows.
+    fn cell<'a>(&self, line: &'a str, esc: bool) -> Vec<Piece<'a>> {
+        self.cell_within(line, esc, None)
+    }
+
+    /// Splits a line of the first text into the pieces of its rows, wrapped at the width if any.
+    fn cell_within<'a>(&self, line: &'a str, esc: bool, width: Option<usize>) -> Vec<Piece<'a>> {
+        let (lead, line) = self.leading(line);
+        let texts = match width {
+            Some(width) => self
+                .measure()
+                .wrap_line(line, width, self.hanging_indent, esc),
+            None => self.wrap(line, 0, esc),
+        };
+        let last = texts.len() - 1;
+        let mut open = Vec::new();
+
+        texts
+            .into_iter()
+            .enumerate()
+            .map(|(index, text)| {
+                let piece_open = open.clone();
+                if esc {
+                    sgr_state(text, &mut open);
+                }
+                let indent = lead + self.indent(index);
+                Piece {
+                    open: piece_open,
+                    text,
+                    close: index != last && !open.is_empty(),
+                    indent,
+                    width: indent + self.measure().line_len(text, esc),
+                }
+            })
+            .collect()
+    }
+}
+
+impl EmptyPolicy {
+    /// Returns true if the row is omitted.
+    #[inline]
+    fn skips(self, row: &Cells) -> bool {
+        let left_empty = row.left_width() == 0;
+        let right_empty = row.right.is_none_or(str::is_empty);
+        match self {
+            Self::Never => false,
+            Self::BothEmpty => left_empty && right_empty,
+            Self::RightEmpty => right_empty,
+            Self::AnyEmpty => left_empty || right_empty,
+        }
+    }
+}
+
+/// The widths of the columns of the combined texts.
+#[derive(Clone, Copy)]
+struct Grid {
+    esc: bool,
+    full: bool,
+    number_width: usize,
+    prefix_len: usize,
+    max_line_two: usize,
+    width_one: usize,
+    width_two: usize,
+    lead: usize,
+}
+
+/// A text fragment of the combined texts, the fill and the row ends told apart from the text.
+#[derive(Clone, Copy)]
+enum Fragment<'a> {
+    Text(&'a str),
+    Fill(&'a str),
+    /// A newline, the row separator or the block terminator.
+    RowEnd(&'a str),
+}
+
+/// The kind of a fragment with the runs of fill joined.
+#[derive(Clone, Copy)]
+enum Run {
+    Text,
+    RowEnd,
+    /// A run of the number of fills.
+    Fill(usize),
+}
+
+impl<'a> Fragment<'a> {
+    /// Returns the text of the fragment.
+    #[inline]
+    fn text(self) -> &'a str {
+        match self {
+            Fragment::Text(text) | Fragment::Fill(text) | Fragment::RowEnd(text) => text,
+        }
+    }
+}
+
+/// One of two iterators over the same items.
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: Iterator, R: Iterator<Item = L::Item>> Iterator for Either<L, R> {
+    type Item = L::Item;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Either::Left(iter) => iter.next(),
+            Either::Right(iter) => iter.next(),
+        }
+    }
+}
+
+/// A text fragment truncated by [`Clip`], keeping its kind.
+trait ClipFragment<'a>: Copy {
+    /// Returns the text of the fragment.
+    fn text(self) -> &'a str;
+
+    /// Returns a fragment of the same kind with the text.
+    fn with_text(self, text: &'a str) -> Self;
+
+    /// Returns true if the fragment ends a row.
+    fn is_row_end(self) -> bool;
+}
+
+impl<'a> ClipFragment<'a> for &'a str {
+    #[inline]
+    fn text(self) -> &'a str {
+        self
+    }
+
+    #[inline]
+    fn with_text(self, text: &'a str) -> Self {
+        text
+    }
+
+    #[inline]
+    fn is_row_end(self) -> bool {
+        self == "\n"
+    }
+}
+
+impl<'a> ClipFragment<'a> for Fragment<'a> {
+    #[inline]
+    fn text(self) -> &'a str {
+        Fragment::text(self)
+    }
+
+    #[inline]
+    fn with_text(self, text: &'a str) -> Self {
+        match self {
+            Fragment::Text(_) => Fragment::Text(text),
+            Fragment::Fill(_) => Fragment::Fill(text),
+            Fragment::RowEnd(_) => Fragment::RowEnd(text),
+        }
+    }
+
+    #[inline]
+    fn is_row_end(self) -> bool {
+        matches!(self, Fragment::RowEnd(_))
+    }
+}
+
+/// The rows of a grid, collected if the widths depend on all of them.
+type GridRows<'a> = Either<vec::IntoIter<Cells<'a>>, Rows<'a>>;
+
+/// The fragments of the rows of a grid with the row ends and the borders of the box.
+///
+/// - The fragments of a row are taken into the buffer, its capacity is reused.
+struct GridFragments<'a, R> {
+    cattocol: &'a CatToCol,
+    grid: Grid,
+    rows: R,
+    next: Option<Cells<'a>>,
+    index: usize,
+    borders: bool,
+    buf: Vec<Fragment<'a>>,
+    pos: usize,
+}
+
+impl<'a, R> GridFragments<'a, R> {
+    /// Takes a horizontal line of the box with the given corners into the buffer.
+    fn push_border(&mut self, corners: [&'a str; 3]) {
+        let cattocol = self.cattocol;
+        let grid = self.grid;
+        self.buf.extend(
+            iter::repeat_n(" ", number_len(grid.number_width))
+                .chain(cattocol.border(corners, grid.width_one, grid.width_two))
+                .map(Fragment::Text),
+        );
+        self.buf.push(Fragment::RowEnd("\n"));
+    }
+}
+
+impl<'a, R: Iterator<Item = Cells<'a>>> Iterator for GridFragments<'a, R> {
+    type Item = Fragment<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(&fragment) = self.buf.get(self.pos) {
+                self.pos += 1;
+                return Some(fragment);
+            }
+            self.buf.clear();
+            self.pos = 0;
+            match self.next.take() {
+                Some(cells) => {
+                    let fragments = self.cattocol.row_fragments(self.grid, self.index, cells);
+                    self.buf.extend(fragments);
+                    self.buf.push(Fragment::RowEnd("\n"));
+                    self.index += 1;
+                    self.next = self.rows.next();
+                }
+                None if self.borders => {
+                    self.borders = false;
+                    self.push_border(["└", "┴", "┘"]);
+                }
+                None => return None,
+            }
+        }
+    }
+}
+
+/// A left cell split into pieces and a right line.
+type LinePair<'a> = (Option<Vec<Piece<'a>>>, Option<&'a str>);
+
+/// The pairs of lines of the texts, as many as the tail policy takes.
+struct LinePairs<'a> {
+    cattocol: &'a CatToCol,
+    esc: bool,
+    lines_one: TextLines<'a>,
+    lines_two: TextLines<'a>,
+    tail_one: Option<&'a str>,
+    tail_two: Option<&'a str>,
+    width_one: Option<usize>,
+}
+
+impl<'a> Iterator for LinePairs<'a> {
+    type Item = LinePair<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cattocol = self.cattocol;
+        let (line_one, line_two) = match (self.lines_one.next(), self.lines_two.next()) {
+            (None, None) => return None,
+            (None, _) | (_, None) if cattocol.tail_policy == TailPolicy::Truncate => return None,
+            lines => lines,
+        };
+        let line_one = empty_or(
+            line_one.or(self.tail_one),
+            cattocol.empty_left_cell.as_deref(),
+        );
+        let line_two = empty_or(line_two.or(self.tail_two), cattocol.empty_cell.as_deref());
+        let line_two = line_two.map(|line| match cattocol.column_width(1) {
+            Some(width) => &line[..cattocol.measure().cut_index(line, width, self.esc).0],
+            None => line,
+        });
+        let cell_one = line_one.map(|line| cattocol.cell_within(line, self.esc, self.width_one));
+        Some((cell_one, line_two))
+    }
+}
+
+/// The rows of the cells of the line pairs.
+///
+/// - The width of the first column grows with the rows if it is running.
+struct Rows<'a> {
+    cattocol: &'a CatToCol,
+    lines: Either<vec::IntoIter<LinePair<'a>>, LinePairs<'a>>,
+    max_line_one: usize,
+    cells: vec::IntoIter<Cells<'a>>,
+    running: Option<usize>,
+    esc: bool,
+    width_two: Option<usize>,
+}
+
+impl<'a> Iterator for Rows<'a> {
+    type Item = Cells<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(mut row) = self.cells.next() else {
+                let (cell_one, line_two) = self.lines.next()?;
+                self.cells = self
+                    .cattocol
+                    .row_cells(
+                        cell_one,
+                        line_two,
+                        self.max_line_one,
+                        self.width_two,
+                        self.esc,
+                    )
+                    .into_iter();
+                continue;
+            };
+            if self.cattocol.skip_empty_rows.skips(&row) {
+                continue;
+            }
+            if let Some(width) = &mut self.running {
+                *width = max(*width, row.left_width());
+                row.column = *width;
+            }
+            return Some(row);
+        }
+    }
+}
+
+/// A piece of a line that fits into one row.
+struct Piece<'a> {
+    open: Vec<&'a str>,
+    text: &'a str,
+    close: bool,
+    indent: usize,
+    width: usize,
+}
+
+/// An iterator over the lines of a text.
+#[derive(Clone, Debug)]
+enum TextLines<'a> {
+    /// Lines ending with a newline.
+    Lines(std::str::Lines<'a>),
+    /// The rest of a text with lines ending with any of the break characters.
+    Breaks(&'a str, Vec<char>),
+}
+
+impl<'a> Iterator for TextLines<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Lines(lines) => lines.next(),
+            Self::Breaks("", _) => None,
+            Self::Breaks(text, breaks) => {
+                let (line, rest) = match text.find(breaks.as_slice()) {
+                    Some(pos) => {
+                        let line = &text[..pos];
+                        let rest = &text[pos..];
+                        match rest.chars().next() {
+                            Some('\r') if rest.starts_with("\r\n") => (line, &rest[2..]),
+                            Some('\n') => (line.strip_suffix('\r').unwrap_or(line), &rest[1..]),
+                            Some(chr) => (line, &rest[chr.len_utf8()..]),
+                            None => (line, rest),
+                        }
+                    }
+                    None => (*text, ""),
+                };
+                *text = rest;
+                Some(line)
+            }
+        }
+    }
+}
+
+/// The cells of a row of the combined texts.
+struct Cells<'a> {
+    left: Option<Piece<'a>>,
+    right: Option<&'a str>,
+    right_indent: usize,
+    column: usize,
+}
+
+impl<'a> Cells<'a> {
+    #[inline]
+    fn left_width(&self) -> usize {
+        self.left.as_ref().map_or(0, |piece| piece.width)
+    }
+
+    /// Returns the text fragments of the left cell.
+    fn into_left(self) -> impl Iterator<Item = &'a str> {
+        self.left.into_iter().flat_map(|piece| {
+            iter::repeat_n(" ", piece.indent)
+                .chain(piece.open)
+                .chain(iter::once(piece.text))
+                .chain(iter::once(SGR_RESET).take(piece.close as usize))
+        })
+    }
+}
+
+/// Combining a text as the first column with another text.
+pub trait CombineExt: AsRef<str> {
+    /// Combines the text with the other text as by [`CatToCol::combine_col`].
+    ///
+    /// ```
+    /// use cattocol::{CatToCol, CombineExt};
+    ///
+    /// let cat_to_col = CatToCol::new().repeat(1);
+    ///
+    /// assert_eq!("one\nthree".combine_col_with("1\n3", &cat_to_col), "one   1\nthree 3\n");
+    /// ```
+    #[inline]
+    fn combine_col_with(&self, other: &str, cfg: &CatToCol) -> String {
+        cfg.combine_col(self.as_ref(), other).collect()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> CombineExt for T {}
+
+/// Concatenating two texts line by line returns an iterator.
+///
+/// - Empty lines of the first text are concatenated with spaces.
+/// - No lines are ignored.
+/// - Every row ends with a newline, whether the texts end with one or not.
+/// # Examples
+///
+/// ```
+/// use cattocol::cat_to_col;
+/// let first_txt = "Combine\ntexts\none\nlinewise.\n\n";
+/// let second_txt = "two\ninto\ntext\n";
+/// let text = "Combine two\ntexts into\none text\nlinewise.\n\n";
+/// let concatenated_txt = cat_to_col(&first_txt, &second_txt).collect::<String>();
+///
+/// assert_eq!(concatenated_txt, text);
+///
+/// let first_txt = "Combine\ntexts\none\n";
+/// let second_txt = "two\ninto\ntext\nlinewise.\n\n";
+/// let text = "Combine two\ntexts into\none text\nlinewise.\n\n";
+/// let concatenated_txt = cat_to_col(&first_txt, &second_txt).collect::<String>();
+///
+/// assert_eq!(concatenated_txt, text);
+/// ```
+#[inline]
+pub fn cat_to_col<'a>(str_one: &'a str, str_two: &'a str) -> impl Iterator<Item = &'a str> {
+    let iter_one = str_one.lines();
+    let iter_two = str_two.lines();
+    let len_min = min(iter_one.clone().count(), iter_two.clone().count());
+    let txt_iter = iter_one.clone().zip(iter_two.clone());
+
+    txt_iter
+        .flat_map(move |item| {
+            iter::once(item.0)
+                .chain(iter::once(" "))
+                .chain(iter::once(item.1))
+                .chain(iter::once("\n"))
+        })
+        .chain(
+            iter_one
+                .skip(len_min)
+                .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
+        )
+        .chain(
+            iter_two
+                .skip(len_min)
+                .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
+        )
+}
+
+/// Concatenating two texts line by line, the remaining lines of the second text indented.
+///
+/// - Same as [`cat_to_col`] while both texts have lines.
+/// - The remaining lines of the second text are indented by the widest line of the first and a space.
+/// - The remaining lines of the first text are not indented.
+/// # Examples
+///
+/// ```
+/// use cattocol::cat_to_col_aligned;
+///
+/// let first_txt = "Combine\ntexts";
+/// let second_txt = "two\ninto\none text\nlinewise.";
+/// let text = "Combine two\ntexts into\n        one text\n        linewise.\n";
+/// let concatenated_txt = cat_to_col_aligned(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!(concatenated_txt, text);
+/// ```
+#[inline]
+pub fn cat_to_col_aligned<'a>(str_one: &'a str, str_two: &'a str) -> impl Iterator<Item = &'a str> {
+    let len_min = min(str_one.lines().count(), str_two.lines().count());
+    let indent = str_one
+        .lines()
+        .map(UnicodeWidthStr::width)
+        .max()
+        .map_or(0, |width| width + 1);
+
+    str_one
+        .lines()
+        .zip(str_two.lines())
+        .flat_map(|(line_one, line_two)| {
+            iter::once(line_one)
+                .chain(iter::once(" "))
+                .chain(iter::once(line_two))
+                .chain(iter::once("\n"))
+        })
+        .chain(
+            str_one
+                .lines()
+                .skip(len_min)
+                .flat_map(|line| iter::once(line).chain(iter::once("\n"))),
+        )
+        .chain(str_two.lines().skip(len_min).flat_map(move |line| {
+            iter::repeat_n(" ", indent)
+                .chain(iter::once(line))
+                .chain(iter::once("\n"))
+        }))
+}
+
+/// Concatenating two texts along the lines of the first text returns an iterator.
+///
+/// - Lines are joined by whitespace.
+/// - If the first text ends, the remaining lines of the second text are ignored.
+/// - No spaces are inserted before or after empty lines.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_lines;
+///
+/// let first_txt = "One green\nrides down";
+/// let second_txt = "brutal tractor\nthe street.";
+/// let concatenated_txt = by_lines(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!(&concatenated_txt, "One green brutal tractor\nrides down the street.\n");
+///
+/// let first_txt = "One green\nrides down";
+/// let second_txt = "brutal tractor\nthe street.\nThe tractor\nhums and smokes.";
+/// let concatenated_txt = by_lines(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!(&concatenated_txt, "One green brutal tractor\nrides down the street.\n");
+/// ```
+#[inline]
+pub fn by_lines<'a>(first_str: &'a str, second_str: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let first_iter = first_str.lines();
+    let mut second_iter = second_str.lines();
+
+    first_iter.flat_map(move |first_line| {
+        let mut space_take = 0;
+        let second_line = if let Some(line) = second_iter.next() {
+            if first_line.is_empty() || line.is_empty() {
+                space_take = 0
+            } else {
+                space_take = 1
+            };
+            line
+        } else {
+            ""
+        }
+        .lines();
+        iter::once(first_line).chain(
+            iter::once(" ")
+                .take(space_take)
+                .chain(second_line)
+                .chain(iter::once("\n")),
+        )
+    })
+}
+
+/// Appending the same note to every line of a text returns an iterator.
+///
+/// - Lines are joined by whitespace.
+/// - No spaces are inserted before or after empty lines.
+/// - The note is expected to be a single line.
+/// # Examples
+///
+/// ```
+/// use cattocol::annotate_lines;
+///
+/// let concatenated_txt = annotate_lines("let a;\n\nlet b;", "// TODO").collect::<String>();
+///
+/// assert_eq!(&concatenated_txt, "let a; // TODO\n// TODO\nlet b; // TODO\n");
+/// ```
+#[inline]
+pub fn annotate_lines<'a>(first_str: &'a str, note: &'a str) -> impl Iterator<Item = &'a str> {
+    first_str.lines().flat_map(move |first_line| {
+        let space_take = usize::from(!first_line.is_empty() && !note.is_empty());
+        iter::once(first_line)
+            .chain(iter::once(" ").take(space_take))
+            .chain(iter::once(note))
+            .chain(iter::once("\n"))
+    })
+}
+
+/// A builder of rows from a left text and the lines of the right text as they arrive.
+///
+/// - Without the ansi escpe sequences.
+/// - The left text is always the left column, the direction is ignored.
+/// - The right column is never filled up, the boxed frame is not drawn.
+#[derive(Debug)]
+pub struct ColumnBuilder<'a> {
+    cattocol: &'a CatToCol,
+    lines: TextLines<'a>,
+    max_line_one: usize,
+    index: usize,
+}
+
+impl<'a> ColumnBuilder<'a> {
+    /// Creates a new ColumnBuilder measuring the left text.
+    pub fn new(cattocol: &'a CatToCol, str_one: &'a str) -> Self {
+        let max_line_one = cattocol
+            .lines(str_one)
+            .filter_map(|line| empty_or(Some(line), cattocol.empty_left_cell.as_deref()))
+            .flat_map(|line| cattocol.cell(line, false))
+            .map(|piece| piece.width)
+            .max()
+            .unwrap_or(0)
+            .max(cattocol.min_column_width);
+        Self {
+            cattocol,
+            lines: cattocol.lines(str_one),
+            max_line_one,
+            index: 0,
+        }
+    }
+
+    /// Returns the rows of the next left line and the right line, each with a newline.
+    pub fn push_right_line(&mut self, line: &str) -> String {
+        let line_two = empty_or(Some(line), self.cattocol.empty_cell.as_deref());
+        self.next_rows(line_two)
+    }
+
+    /// Returns the rows of the remaining left lines, each with a newline.
+    pub fn finish(mut self) -> String {
+        let mut text = String::new();
+        while self.lines.clone().next().is_some() {
+            let line_two = empty_or(None, self.cattocol.empty_cell.as_deref());
+            text.push_str(&self.next_rows(line_two));
+        }
+        text
+    }
+
+    fn next_rows(&mut self, line_two: Option<&str>) -> String {
+        let cattocol = self.cattocol;
+        let cell_one = empty_or(self.lines.next(), cattocol.empty_left_cell.as_deref())
+            .map(|line| cattocol.cell(line, false));
+        let grid = Grid {
+            esc: false,
+            full: false,
+            number_width: 0,
+            prefix_len: cattocol.measure().str_width(&cattocol.prefix),
+            max_line_two: 0,
+            width_one: self.max_line_one + cattocol.repeat,
+            width_two: 0,
+            lead: cattocol.repeat * usize::from(cattocol.symmetric_gap),
+        };
+        let mut text = String::new();
+        for cells in cattocol.row_cells(cell_one, line_two, self.max_line_one, None, false) {
+            text.extend(Clip::new(
+                cattocol.row(grid, self.index, cells),
+                cattocol,
+                false,
+            ));
+            text.push('\n');
+            self.index += 1;
+        }
+        text
+    }
+}
+
+/// A row of [`CatToCol::row_iter`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Row<'a> {
+    left: &'a str,
+    right: Option<&'a str>,
+    width: usize,
+    tail: bool,
+    text: String,
+}
+
+impl<'a> Row<'a> {
+    /// Returns the text of the left cell, empty after the end of the first column.
+    #[inline]
+    pub fn left(&self) -> &'a str {
+        self.left
+    }
+
+    /// Returns the text of the right cell, if the row has one.
+    #[inline]
+    pub fn right(&self) -> Option<&'a str> {
+        self.right
+    }
+
+    /// Returns the width of the formatted row.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns true if the row is after the end of the first column.
+    #[inline]
+    pub fn is_tail(&self) -> bool {
+        self.tail
+    }
+}
+
+impl fmt::Display for Row<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// An iterator over the text fragments of [`CatToCol::combine_col`] and [`CatToCol::combine_col_esc`].
+pub struct CombineColIter<'a> {
+    iter: Clip<'a, GridFragments<'a, GridRows<'a>>>,
+}
+
+impl<'a> Iterator for CombineColIter<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Fragment::text)
+    }
+}
+
+impl fmt::Debug for CombineColIter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CombineColIter").finish_non_exhaustive()
+    }
+}
+
+/// The text of [`CatToCol::combine_col_measured`] with its dimensions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Combined {
+    /// The combined text.
+    pub text: String,
+    /// The width of the widest row.
+    pub width: usize,
+    /// The number of rows.
+    pub rows: usize,
+}
+
+/// The text of [`CatToCol::combine_col_buffer`] with the ranges of its rows.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CombinedBuffer {
+    text: String,
+    rows: Vec<Range<usize>>,
+}
+
+impl CombinedBuffer {
+    /// Returns the rows without the row separator, borrowed from the text.
+    #[inline]
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.rows.iter().map(|row| &self.text[row.clone()])
+    }
+
+    /// Returns the combined text.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns the combined text, the ranges of the rows are dropped.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.text
+    }
+}
+
+/// The numbers of lines left without a partner by [`by_lines_reported`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LinesReport {
+    /// Lines of the second text ignored after the first text ended.
+    pub dropped: usize,
+    /// Lines of the first text without a line of the second text.
+    pub unpaired: usize,
+}
+
+/// Concatenating two texts along the lines of the first text returns an iterator and a report.
+///
+/// - Same as [`by_lines`].
+/// - The report counts the lines without a partner.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_lines_reported;
+///
+/// let first_txt = "One green\nrides down";
+/// let second_txt = "brutal tractor\nthe street.\nThe tractor\nhums and smokes.";
+/// let (iter, report) = by_lines_reported(first_txt, second_txt);
+///
+/// assert_eq!(&iter.collect::<String>(), "One green brutal tractor\nrides down the street.\n");
+/// assert_eq!(report.dropped, 2);
+/// assert_eq!(report.unpaired, 0);
+/// ```
+#[inline]
+pub fn by_lines_reported<'a>(
+    first_str: &'a str,
+    second_str: &'a str,
+) -> (impl Iterator<Item = &'a str> + 'a, LinesReport) {
+    let first_len = first_str.lines().count();
+    let second_len = second_str.lines().count();
+    let report = LinesReport {
+        dropped: second_len.saturating_sub(first_len),
+        unpaired: first_len.saturating_sub(second_len),
+    };
+
+    (by_lines(first_str, second_str), report)
+}
+
+/// Concatenating two texts by lines parwise returns an iterator.
+///
+/// - Lines are joined by whitespace.
+/// - Unpaired and empty lines are ignored.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_pairs;
+///
+/// let first_txt = "one horsepower\ntwo horsepower\nthree horsepower\nfour horsepower\n";
+/// let second_txt = "per horse\ntwo horses\n";
+/// let concatenated_txt = by_pairs(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!( &concatenated_txt, "one horsepower per horse\ntwo horsepower two horses\n");
+///
+/// let first_txt = "red apple\ngreen pear\nyellow tomato\npurple eggplant\n";
+/// let second_txt = "";
+/// let concatenated_txt = by_pairs(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!( &concatenated_txt, "");
+/// ```
+#[inline]
+pub fn by_pairs<'a>(first_str: &'a str, second_str: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let first_iter = first_str.lines();
+    let mut second_iter = second_str.lines();
+
+    first_iter.flat_map(move |first_line| {
+        let mut takes = 0;
+        let second_line = if let Some(line) = second_iter.next() {
+            takes = usize::MAX;
+            line
+        } else {
+            ""
+        };
+
+        if first_line.is_empty() || second_line.is_empty() {
+            takes = 0;
+        };
+
+        iter::once(first_line)
+            .chain(
+                iter::once(" ")
+                    .chain(second_line.lines())
+                    .chain(iter::once("\n")),
+            )
+            .take(takes)
+    })
+}
+
+/// Interleaving the lines of two texts returns an iterator.
+///
+/// - Lines of the first and the second text alternate.
+/// - The remaining lines of the longer text are appended.
+/// - Empty lines are preserved.
+/// # Examples
+///
+/// ```
+/// use cattocol::interleave_lines;
+///
+/// let first_txt = "one\nthree\nfive\nsix\n";
+/// let second_txt = "two\nfour\n";
+/// let interleaved_txt = interleave_lines(first_txt, second_txt).collect::<String>();
+///
+/// assert_eq!(&interleaved_txt, "one\ntwo\nthree\nfour\nfive\nsix\n");
+/// ```
+#[inline]
+pub fn interleave_lines<'a>(
+    first_str: &'a str,
+    second_str: &'a str,
+) -> impl Iterator<Item = &'a str> + 'a {
+    let len_max = max(first_str.lines().count(), second_str.lines().count());
+    let first_iter = first_str.lines().map(Some).chain(iter::repeat(None));
+    let second_iter = second_str.lines().map(Some).chain(iter::repeat(None));
+
+    first_iter
+        .zip(second_iter)
+        .take(len_max)
+        .flat_map(|(first_line, second_line)| {
+            first_line
+                .into_iter()
+                .chain(second_line)
+                .flat_map(|line| iter::once(line).chain(iter::once("\n")))
+        })
+}
+
+/// Concatenating three texts along the lines of the first text returns an iterator.
+///
+/// - Lines are joined by whitespace.
+/// - If the first text ends, the remaining lines of the second text are ignored.
+/// - No spaces are inserted before or after empty lines.
+/// - A line is preceded by a space only if an earlier line of the row is not empty.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_three_lines;
+///
+/// let first_txt = "One season\nDecembre,\nIt's cold.\n";
+/// let second_txt = "a year\nJanuary,\n";
+/// let third_txt = "is winter.\nFebruary.\n";
+/// let text = "One season a year is winter.\nDecembre, January, February.\nIt's cold.\n";
+/// let concatenated_txt = by_three_lines(first_txt, second_txt, third_txt).collect::<String>();
+///
+/// assert_eq!( &concatenated_txt, text);
+///
+/// ```
+#[inline]
+pub fn by_three_lines<'a>(
+    first_str: &'a str,
+    second_str: &'a str,
+    third_str: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    let first_iter = first_str.lines();
+    let mut second_iter = second_str.lines();
+    let mut third_iter = third_str.lines();
+
+    first_iter.flat_map(move |first_line| {
+        let mut first_space_take = 0;
+        let mut second_space_take = 0;
+        let first_line_notempty = !first_line.is_empty();
+        let mut second_line_notempty = false;
+        let mut second_line = "";
+        let mut third_line = "";
+
+        if let Some(line) = second_iter.next() {
+            second_line_notempty = !line.is_empty();
+            if first_line_notempty && second_line_notempty {
+                first_space_take = 1;
+            };
+            second_line = line;
+        }
+
+        if let Some(line) = third_iter.next() {
+            if (first_line_notempty || second_line_notempty) && !line.is_empty() {
+                second_space_take = 1;
+            };
+            third_line = line;
+        }
+
+        iter::once(first_line)
+            .chain(
+                iter::once(" ")
+                    .take(first_space_take)
+                    .chain(second_line.lines()),
+            )
+            .chain(
+                iter::once(" ")
+                    .take(second_space_take)
+                    .chain(third_line.lines()),
+            )
+            .chain(iter::once("\n"))
+    })
+}
+
+/// Concatenating four texts along the lines of the first text returns an iterator.
+///
+/// - Lines are joined by whitespace.
+/// - If the first text ends, the remaining lines of the second text are ignored.
+/// - No spaces are inserted before or after empty lines.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_four_lines;
+///
+/// let first_txt = "One in english,\nEin in german,\n";
+/// let second_txt = "two in english,\nzwei in german,\n";
+/// let third_txt = "three in english,\ndrei in german,\n";
+/// let fourth_txt = "four in english.\nvier in german.\n";
+/// let text = "One in english, two in english, three in english, four in english.\n\
+///     Ein in german, zwei in german, drei in german, vier in german.\n";
+/// let concatenated_txt = by_four_lines(first_txt, second_txt, third_txt, fourth_txt).collect::<String>();
+///
+/// assert_eq!(&concatenated_txt, text);
+/// ```
+#[inline]
+pub fn by_four_lines<'a>(
+    first_str: &'a str,
+    second_str: &'a str,
+    third_str: &'a str,
+    fourth_str: &'a str,
+) -> impl Iterator<Item = &'a str> {
+    let first_iter = first_str.lines();
+    let mut second_iter = second_str.lines();
+    let mut third_iter = third_str.lines();
+    let mut fourth_iter = fourth_str.lines();
+
+    first_iter.flat_map(move |first_line| {
+        let mut first_space_take = 0;
+        let mut second_space_take = 0;
+        let mut third_space_take = 0;
+        let first_line_notempty = !first_line.is_empty();
+        let mut second_line_notempty = false;
+        let mut third_line_notempty = false;
+        let mut second_line = "";
+        let mut third_line = "";
+        let mut fourth_line = "";
+
+        if let Some(line) = second_iter.next() {
+            second_line_notempty = !line.is_empty();
+            if first_line_notempty && second_line_notempty {
+                first_space_take = 1;
+            };
+            second_line = line;
+        }
+
+        if let Some(line) = third_iter.next() {
+            third_line_notempty = !line.is_empty();
+            if (first_line_notempty || second_line_notempty) && !line.is_empty() {
+                second_space_take = 1;
+            };
+            third_line = line;
+        }
+
+        if let Some(line) = fourth_iter.next() {
+            if (first_line_notempty || second_line_notempty || third_line_notempty)
+                && !line.is_empty()
+            {
+                third_space_take = 1;
+            };
+            fourth_line = line;
+        }
+
+        iter::once(first_line)
+            .chain(
+                iter::once(" ")
+                    .take(first_space_take)
+                    .chain(second_line.lines()),
+            )
+            .chain(
+                iter::once(" ")
+                    .take(second_space_take)
+                    .chain(third_line.lines()),
+            )
+            .chain(
+                iter::once(" ")
+                    .take(third_space_take)
+                    .chain(fourth_line.lines()),
+            )
+            .chain(iter::once("\n"))
+    })
+}
+
+/// Concatenating any number of texts along the lines of the first text returns an iterator.
+///
+/// - The lines of the text `i` and `i + 1` are joined by the separator `i`.
+/// - Missing separators are a single space.
+/// - If the first text ends, the remaining lines of the other texts are ignored.
+/// - A line is preceded by its separator only if it and an earlier line of the row are not empty.
+/// # Examples
+///
+/// ```
+/// use cattocol::by_columns_sep;
+///
+/// let texts = ["one\ntwo\n", "1\n\n", "I\nII\n"];
+/// let concatenated_txt = by_columns_sep(&texts, &[" | ", " - "]).collect::<String>();
+///
+/// assert_eq!(&concatenated_txt, "one | 1 - I\ntwo - II\n");
+/// ```
+#[inline]
+pub fn by_columns_sep<'a>(
+    texts: &'a [&'a str],
+    seps: &'a [&'a str],
+) -> impl Iterator<Item = &'a str> {
+    let mut iters = texts.iter().map(|text| text.lines()).collect::<Vec<_>>();
+    let first_iter = match iters.is_empty() {
+        true => "".lines(),
+        false => iters.remove(0),
+    };
+
+    first_iter.flat_map(move |first_line| {
+        let mut row = vec![first_line];
+        let mut row_notempty = !first_line.is_empty();
+
+        for (index, iter) in iters.iter_mut().enumerate() {
+            let line = iter.next().unwrap_or_default();
+            if row_notempty && !line.is_empty() {
+                row.push(seps.get(index).copied().unwrap_or(" "));
+            }
+            row_notempty |= !line.is_empty();
+            row.push(line);
+        }
+        row.push("\n");
+        row
+    })
+}
+
+/// Returns the display width of the longest line of a text without the ansi escape sequences.
+///
+/// - Scans the text in place, no stripped copy is allocated.
+/// - The control characters other than the newline are zero width.
+/// # Examples
+///
+/// ```
+/// use cattocol::display_width_no_esc;
+///
+/// assert_eq!(display_width_no_esc("\x1b[1;31mnortherly\x1b[0m wind"), 14);
+/// assert_eq!(display_width_no_esc("\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\"), 4);
+/// assert_eq!(display_width_no_esc("北風\n\x1b[7mwind\x1b[0m"), 4);
+/// ```
+#[inline]
+pub fn display_width_no_esc(text: &str) -> usize {
+    CatToCol::new()
+        .control_width(ControlPolicy::Zero)
+        .measure()
+        .line_len_no_esc(text)
+}
+
+/// Truncates a text to a display width, ending it with the ellipsis.
+///
+/// - Grapheme clusters are never split.
+/// - The ellipsis is counted into the width.
+/// - A text that fits is returned unchanged.
+/// # Examples
+///
+/// ```
+/// use cattocol::truncate_to_width;
+///
+/// assert_eq!(truncate_to_width("northerly wind", 8, "…"), "norther…");
+/// assert_eq!(truncate_to_width("北風が吹く", 7, "…"), "北風が…");
+/// assert_eq!(truncate_to_width("wind", 8, "…"), "wind");
+/// ```
+pub fn truncate_to_width(text: &str, width: usize, ellipsis: &str) -> String {
+    if text.width() <= width {
+        return text.to_string();
+    }
+
+    let ellipsis_width = ellipsis.width();
+    if ellipsis_width > width {
+        return truncate_to_width(ellipsis, width, "");
+    }
+
+    let mut truncated = String::new();
+    let mut truncated_width = 0;
+    for grapheme in text.graphemes(true) {
+        truncated_width += grapheme.width();
+        if truncated_width + ellipsis_width > width {
+            break;
+        }
+        truncated.push_str(grapheme);
+    }
+    truncated.push_str(ellipsis);
+    truncated
+}
+
+/// Writes the text fragments through a buffer of `WRITE_BUF_LEN` bytes.
+fn write_batched<'a, W: Write>(
+    writer: &mut W,
+    fragments: impl Iterator<Item = &'a str>,
+) -> io::Result<()> {
+    let mut buf = String::with_capacity(WRITE_BUF_LEN);
+    for fragment in fragments {
+        if buf.len() + fragment.len() > WRITE_BUF_LEN {
+            writer.write_all(buf.as_bytes())?;
+            buf.clear();
+        }
+        if fragment.len() > WRITE_BUF_LEN {
+            writer.write_all(fragment.as_bytes())?;
+        } else {
+            buf.push_str(fragment);
+        }
+    }
+    if !buf.is_empty() {
+        writer.write_all(buf.as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Truncates a text to a display width, with the ellipsis at the place of the mode.
+///
+/// - Grapheme clusters are never split.
+/// - The ellipsis is counted into the width.
+/// - In the middle mode the end of the text takes at most half of the width left by the ellipsis.
+/// # Examples
+///
+/// ```
+/// use cattocol::{truncate_with, TruncateMode};
+///
+/// let path = "/usr/share/doc/cattocol/README.md";
+///
+/// assert_eq!(truncate_with(path, 15, "…", TruncateMode::Middle), "/usr/sh…ADME.md");
+/// assert_eq!(truncate_with(path, 15, "…", TruncateMode::End), "/usr/share/doc…");
+/// ```
+pub fn truncate_with(text: &str, width: usize, ellipsis: &str, mode: TruncateMode) -> String {
+    let ellipsis_width = ellipsis.width();
+    if mode == TruncateMode::End || text.width() <= width || ellipsis_width > width {
+        return truncate_to_width(text, width, ellipsis);
+    }
+
+    let tail_width = (width - ellipsis_width) / 2;
+    let mut tail_start = text.len();
+    let mut tail_taken = 0;
+    for (index, grapheme) in text.grapheme_indices(true).rev() {
+        if tail_taken + grapheme.width() > tail_width {
+            break;
+        }
+        tail_taken += grapheme.width();
+        tail_start = index;
+    }
+    let head = truncate_to_width(text, width - ellipsis_width - tail_taken, "");
+    head + ellipsis + &text[tail_start..]
+}
+
+/// Truncates the rows of a text fragment iterator to a width.
+///
+/// - In the ansi mode escape sequences are zero width and are never dropped.
+struct Clip<'a, I: Iterator> {
+    iter: I,
+    width: Option<usize>,
+    measure: Measure<'a>,
+    esc: bool,
+    col: usize,
+    rest: Option<I::Item>,
+    separator: &'a str,
+    terminator: Option<&'a str>,
+    held: Option<I::Item>,
+}
+
+impl<'a, I: Iterator> Clip<'a, I> {
+    #[inline]
+    fn new(iter: I, cattocol: &'a CatToCol, esc: bool) -> Self {
+        Self {
+            iter,
+            width: cattocol.clip,
+            measure: cattocol.measure(),
+            esc,
+            col: 0,
+            rest: None,
+            separator: &cattocol.row_separator,
+            terminator: cattocol.block_terminator.as_deref(),
+            held: None,
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item: ClipFragment<'a>>> Iterator for Clip<'a, I> {
+    type Item = I::Item;
+
+    /// Replaces the newlines by the row separator and the last one by the block terminator.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.separator == "\n" && self.terminator == Some("\n") {
+            return self.clip_next();
+        }
+        let fragment = match self.held.take() {
+            Some(fragment) => fragment,
+            None => self.clip_next()?,
+        };
+        if !fragment.is_row_end() {
+            return Some(fragment);
+        }
+        match self.clip_next() {
+            Some(next) => {
+                self.held = Some(next);
+                Some(fragment.with_text(self.separator))
+            }
+            None => self
+                .terminator
+                .map(|terminator| fragment.with_text(terminator)),
+        }
+    }
+}
+
+impl<'a, I: Iterator<Item: ClipFragment<'a>>> Clip<'a, I> {
+    /// Returns the next fragment truncated to the width.
+    fn clip_next(&mut self) -> Option<I::Item> {
+        let Some(width) = self.width else {
+            return self.iter.next();
+        };
+
+        loop {
+            let fragment = match self.rest.take() {
+                Some(fragment) => fragment,
+                None => {
+                    let fragment = self.iter.next()?;
+                    if fragment.is_row_end() {
+                        self.col = 0;
+                        return Some(fragment);
+                    }
+                    fragment
+                }
+            };
+
+            let text = fragment.text();
+            let (end, cols) = self.measure.cut_index(text, width - self.col, self.esc);
+            self.col += cols;
+            if end < text.len() {
+                self.col = width;
+            }
+
+            if end < text.len() && self.esc {
+                let rest = &text[end..];
+                let rest = &rest[rest.find('\x1b').unwrap_or(rest.len())..];
+                self.rest = Some(fragment.with_text(rest)).filter(|_| !rest.is_empty());
+            }
+
+            if end > 0 || text.is_empty() {
+                return Some(fragment.with_text(&text[..end]));
+            }
+        }
+    }
+}
+
+/// Updates the list of open SGR sequences with the sequences of the text.
+fn sgr_state<'a>(text: &'a str, open: &mut Vec<&'a str>) {
+    let mut rest = text;
+    while let Some(pos) = rest.find('\x1b') {
+        let len = esc_len(&rest[pos..]);
+        let seq = &rest[pos..pos + len];
+        if let Some(params) = seq
+            .strip_prefix("\x1b[")
+            .and_then(|seq| seq.strip_suffix('m'))
+        {
+            if params.is_empty() || params == "0" {
+                open.clear();
+            } else {
+                open.push(seq);
+            }
+        }
+        rest = &rest[pos + len..];
+    }
+}
+
+/// Returns the byte length of the escape sequence at the start of the text.
+#[inline]
+fn esc_len(text: &str) -> usize {
+    let bytes = text.as_bytes();
+    match bytes.get(1) {
+        Some(b'[') => bytes[2..]
+            .iter()
+            .position(|byte| (0x40..=0x7e).contains(byte))
+            .map_or(bytes.len(), |pos| pos + 3),
+        Some(b']') => (2..bytes.len())
+            .find_map(|pos| match bytes[pos] {
+                0x07 => Some(pos + 1),
+                0x1b if bytes.get(pos + 1) == Some(&b'\\') => Some(pos + 2),
+                _ => None,
+            })
+            .unwrap_or(bytes.len()),
+        Some(_) => 1 + text[1..].chars().next().map_or(0, char::len_utf8),
+        None => 1,
+    }
+}
+
+/// Returns true if the text starts with a terminated CSI or OSC sequence.
+#[inline]
+fn is_complete_esc(text: &str) -> bool {
+    let seq = &text.as_bytes()[..esc_len(text)];
+    match seq {
+        [b'\x1b', b'[', .., last] => (0x40..=0x7e).contains(last),
+        [b'\x1b', b']', ..] => seq.ends_with(b"\x07") || seq.ends_with(b"\x1b\\"),
+        _ => false,
+    }
+}
+
+/// Returns the width of the column of the row numbers with the spaces after them.
+#[inline]
+fn number_len(number_width: usize) -> usize {
+    match number_width {
+        0 => 0,
+        width => width + 2,
+    }
+}
+
+/// Returns the fragments of a row number right-aligned to the width.
+///
+/// - Nothing if the width is zero.
+fn row_number<'a>(number: usize, width: usize) -> impl Iterator<Item = &'a str> {
+    let mut digits = iter::successors(Some(number), |rest| {
+        Some(rest / 10).filter(|rest| *rest > 0)
+    })
+    .filter(|_| width > 0)
+    .map(|rest| &DIGITS[rest % 10..rest % 10 + 1])
+    .collect::<Vec<_>>();
+    digits.reverse();
+
+    iter::repeat_n(" ", width.saturating_sub(digits.len()))
+        .chain(digits)
+        .chain(iter::repeat_n(" ", number_len(width) - width))
+}
+
+/// Returns true for the control characters of the control policy.
+#[inline]
+fn is_control(chr: char) -> bool {
+    chr < ' ' && chr != '\t' || chr == '\x7f'
+}
+
+/// Replaces a missing or empty line with the placeholder.
+#[inline]
+fn empty_or<'a>(line: Option<&'a str>, placeholder: Option<&'a str>) -> Option<&'a str> {
+    match (line, placeholder) {
+        (Some("") | None, Some(placeholder)) => Some(placeholder),
+        (line, _) => line,
+    }
+}
+
+/// The width measurement of a CatToCol.
+#[derive(Clone, Copy)]
+struct Measure<'a> {
+    mode: WidthMode,
+    ambiguous_wide: bool,
+    control: ControlPolicy,
+    overrides: &'a [(char, usize)],
+    /// An escape without a complete sequence is a control character.
+    sanitize: bool,
+}
+
+impl Measure<'_> {
+    /// Returns the width of a text by the width mode only.
+    #[inline]
+    fn mode_width(self, text: &str) -> usize {
+        match self.mode {
+            WidthMode::Chars => text.chars().count(),
+            WidthMode::Bytes => text.len(),
+            WidthMode::Unicode if self.ambiguous_wide => text.width_cjk(),
+            WidthMode::Unicode => text.width(),
+        }
+    }
+
+    /// Returns the width of a text without line breaks.
+    #[inline]
+    fn str_width(self, text: &str) -> usize {
+        let mut width = self.mode_width(text);
+        let mut rest = match self.overrides.is_empty() {
+            true => "",
+            false => text,
+        };
+        while let Some(unit) = self.next_unit(rest) {
+            rest = &rest[unit.len()..];
+            let mut chars = unit.chars().filter(|&chr| !is_control(chr));
+            let (Some(chr), None) = (chars.next(), chars.next()) else {
+                continue;
+            };
+            if let Some(&(_, chr_width)) = self.overrides.iter().find(|(key, _)| *key == chr) {
+                width = width.saturating_sub(self.mode_width(unit)) + chr_width;
+            }
+        }
+        if self.control == ControlPolicy::One {
+            return width;
+        }
+        let controls = text.chars().filter(|&chr| is_control(chr)).count();
+        width - controls + controls * self.control_width()
+    }
+
+    /// Returns true if the text starts with an escape sequence taking no cell.
+    ///
+    /// - A sanitized escape without a complete sequence is a control character.
+    #[inline]
+    fn is_esc(self, text: &str) -> bool {
+        text.starts_with('\x1b') && (!self.sanitize || is_complete_esc(text))
+    }
+
+    /// Returns the width of a control character by the control policy.
+    #[inline]
+    fn control_width(self) -> usize {
+        match self.control {
+            ControlPolicy::Zero => 0,
+            ControlPolicy::One => 1,
+            ControlPolicy::Escape => 2,
+        }
+    }
+
+    #[inline]
+    fn line_len(self, line: &str, esc: bool) -> usize {
+        if esc {
+            self.line_len_no_esc(line)
+        } else {
+            self.str_width(line)
+        }
+    }
+
+    /// Returns the width of the longest line of a text without the escape sequences.
+    ///
+    /// - The control characters other than the newline are as wide as of the control policy.
+    #[inline]
+    fn line_len_no_esc(self, line: &str) -> usize {
+        let mut max_width = 0;
+        let mut width = 0;
+        let mut rest = line;
+        while !rest.is_empty() {
+            let end = rest
+                .find(|chr: char| chr < ' ' || chr == '\x7f')
+                .unwrap_or(rest.len());
+            width += self.str_width(&rest[..end]);
+            rest = &rest[end..];
+            match rest.as_bytes().first() {
+                Some(b'\x1b') if self.is_esc(rest) => rest = &rest[esc_len(rest)..],
+                Some(b'\n') => {
+                    max_width = max(max_width, width);
+                    width = 0;
+                    rest = &rest[1..];
+                }
+                Some(&byte) => {
+                    if is_control(char::from(byte)) {
+                        width += self.control_width();
+                    }
+                    rest = &rest[1..];
+                }
+                None => {}
+            }
+        }
+        max(max_width, width)
+    }
+
+    /// Returns the first character or grapheme cluster of the text.
+    #[inline]
+    fn next_unit(self, text: &str) -> Option<&str> {
+        match self.mode {
+            WidthMode::Chars | WidthMode::Bytes => {
+                text.chars().next().map(|chr| &text[..chr.len_utf8()])
+            }
+            WidthMode::Unicode => text.graphemes(true).next(),
+        }
+    }
+
+    /// Returns the byte index after the text fitting into the width and the width taken.
+    ///
+    /// - In the ansi mode escape sequences are zero width and are taken after the text.
+    #[inline]
+    fn cut_index(self, text: &str, width: usize, esc: bool) -> (usize, usize) {
+        let mut col = 0;
+        let mut end = 0;
+        while let Some(unit) = self.next_unit(&text[end..]) {
+            if esc && self.is_esc(&text[end..]) {
+                end += esc_len(&text[end..]);
+                continue;
+            }
+            let unit_width = self.str_width(unit);
+            if col + unit_width > width {
+                break;
+            }
+            col += unit_width;
+            end += unit.len();
+        }
+        (end, col)
+    }
+
+    /// Splits a line into pieces of at most the given width, breaking at spaces where possible.
+    ///
+    /// - The pieces after the first are narrower by the indent.
+    fn wrap_line(self, line: &str, width: usize, indent: usize, esc: bool) -> Vec<&str> {
+        let mut pieces = Vec::new();
+        let mut rest = line;
+
+        loop {
+            let width = match pieces.is_empty() {
+                true => width,
+                false => width.saturating_sub(indent),
+            };
+            let (cut, _) = self.cut_index(rest, width.max(1), esc);
+            if cut == rest.len() {
+                pieces.push(rest);
+                return pieces;
+            }
+
+            let cut = match cut {
+                0 => self.next_unit(rest).map_or(rest.len(), str::len),
+                cut => cut,
+            };
+            let brk = if rest[cut..].starts_with(' ') {
+                cut
+            } else {
+                rest[..cut]
+                    .rfind(' ')
+                    .filter(|&pos| !rest[..pos].trim_end_matches(' ').is_empty())
+                    .unwrap_or(cut)
+            };
+            pieces.push(rest[..brk].trim_end_matches(' '));
+            rest = rest[brk..].trim_start_matches(' ');
+
+            if rest.is_empty() {
+                return pieces;
+            }
+        }
+    }
+}
+
+#[rustfmt::skip]
+#[cfg(test)]
+#[allow(clippy::needless_borrow)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use strip_ansi_escapes::strip;
+
+    #[test]
+    fn cat_one_two_txt() {
+        let txt_col = "Combine two texts Returns an iterator\ninto one text from one\nfrom two columns. text of two\nmerged columns.\nCollect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn cat_two_one_txt() {
+        let txt_col = "Returns an iterator Combine two texts\nfrom one into one text\ntext of two from two columns.\nmerged columns.\nCollect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col(&txt_two, &txt_one).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn cat_one_empty_txt() {
+        let txt_col = "Combine two texts\ninto one text\nfrom two columns.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let texts = cat_to_col(&txt_one, "").collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn cat_one_empty_line_txt() {
+        let txt_col = "Combine two texts\n\ninto one text\nfrom two columns.\n";
+        let txt_one = "Combine two texts\n\ninto one text\nfrom two columns.";
+        let texts = cat_to_col(&txt_one, "").collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn cat_one_newline_txt() {
+        let txt_col = " Combine two texts\n into one text\nfrom two columns.\n";
+        let txt_two = "Combine two texts\ninto one text\nfrom two columns.";
+        let texts = cat_to_col("\n\n", &txt_two).collect::<String>();
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn cat_empty_txt() {
+        let texts = cat_to_col("", "").collect::<String>();
+        assert_eq!(texts, "");
+    }
+
+    #[test]
+    fn cat_one_space_txt() {
+        let txt_col = "Combine two texts  \ninto one text  \nfrom two columns.  \n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let texts = cat_to_col(&txt_one, " \n \n ").collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_two_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "Combine two texts Returns an iterator\ninto one text     from one\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_newline_two_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "                  Returns an iterator\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
+        let txt_one = "\nfrom two columns.\n\n";
+        let txt_two =
+            "Returns an iterator\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_two_one_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "Returns an iterator Combine two texts\nfrom one            into one text\ntext of two         from two columns.\nmerged columns.\nCollect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_two, &txt_one).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_two_repeat_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(10);
+        let txt_col = "Combine two texts          Returns an iterator\ninto one text              from one\nfrom two columns.          text of two\n                           merged columns.\n                           Collect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_empty_repeat_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(10);
+        let txt_col = "Combine two texts\ninto one text\nfrom two columns.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let texts = cat_to_col.combine_col(&txt_one, "").collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_empty_one_repeat_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(10);
+        let txt_col = "          Combine two texts\n          into one text\n          from two columns.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let texts = cat_to_col.combine_col("", &txt_one).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_two_repeat_fill_txt() {
+        let cat_to_col = CatToCol::new().repeat(10).fill('╍');
+        let txt_col = "Combine two texts╍╍╍╍╍╍╍╍╍╍Returns an iterator\ninto one text╍╍╍╍╍╍╍╍╍╍╍╍╍╍from one\nfrom two columns.╍╍╍╍╍╍╍╍╍╍text of two\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍merged columns.\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍Collect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_one_two_repeat_zero_fill_txt() {
+        let cat_to_col = CatToCol::new().repeat(0).fill('╍');
+        let txt_col = "Combine two textsReturns an iterator\ninto one text╍╍╍╍from one\nfrom two columns.text of two\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍merged columns.\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍Collect to String.\n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{txt_col}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_esc_one_two_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "\x1b[33mCombine\x1b[0m \x1b[36mtwo\x1b[0m texts Returns an iterator\ninto one text     from one\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
+        let txt_one = "\x1b[33mCombine\x1b[0m \x1b[36mtwo\x1b[0m texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col_esc(&txt_one, &txt_two).collect::<String>();
+        println!("\n{txt_one}");
+        println!("\n{txt_two}");
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn test_by_lines_first_gt_second() {
+        let iter = by_lines("one\ntwo\nthree\nprimary\nsecondary\n", "first\nsecond\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\nthree\nprimary\nsecondary\n"
+        );
+    }
+
+    #[test]
+    fn test_by_lines_first_eq_second() {
+        let iter = by_lines("one\ntwo\nthree\n", "first\nsecond\nthird\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\nthree third\n"
+        );
+    }
+
+    #[test]
+    fn test_by_lines_first_lt_second() {
+        let iter = by_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\nthree third\n"
+        );
+    }
+
+    #[test]
+    fn test_by_lines_first_empty() {
+        let iter = by_lines("", "first\nsecond\nthird\nfourth\nfifth\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_lines_second_empty() {
+        let iter = by_lines("one\ntwo\nthree\n", "");
+        assert_eq!(&iter.collect::<String>(), "one\ntwo\nthree\n");
+    }
+
+    #[test]
+    fn test_by_lines_first_second_empty() {
+        let iter = by_lines("", "");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_lines_first_second_newline() {
+        let iter = by_lines("\n", "\n");
+        assert_eq!(&iter.collect::<String>(), "\n");
+    }
+
+    #[test]
+    fn test_by_lines_first_empty_second_newline() {
+        let iter = by_lines("", "\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_lines_first_newline_second_empty() {
+        let iter = by_lines("\n", "");
+        assert_eq!(&iter.collect::<String>(), "\n");
+    }
+
+    #[test]
+    fn test_by_lines_first_two_newline_second_empty() {
+        let iter = by_lines("one\n\ntwo\n\nthree\n\n", "");
+        assert_eq!(&iter.collect::<String>(), "one\n\ntwo\n\nthree\n\n");
+    }
+
+    #[test]
+    fn test_by_lines_first_two_newline() {
+        let iter = by_lines("one\n\ntwo\n\nthree\n\n", "first\nsecond\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\nsecond\ntwo\n\nthree\n\n"
+        );
+    }
+
+    #[test]
+    fn test_by_lines_first_second_two_newline() {
+        let iter = by_lines("one\ntwo\n\nfour\n\nsix\n", "first\n\nthird\nfourth\n\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo\nthird\nfour fourth\n\nsix\n"
+        );
+    }
+
+    #[test]
+    fn test_by_lines_first_newline() {
+        let iter = by_lines("\n\n", "first\nsecond\n");
+        assert_eq!(&iter.collect::<String>(), "first\nsecond\n");
+    }
+
+    #[test]
+    fn test_by_lines_first_newline_gt_second() {
+        let iter = by_lines("\n\n\n\n", "first\nsecond\n");
+        assert_eq!(&iter.collect::<String>(), "first\nsecond\n\n\n");
+    }
+
+    #[test]
+    fn test_by_lines_newlines() {
+        let iter = by_lines("\n\n\n\n", "\n\n\n\n");
+        assert_eq!(&iter.collect::<String>(), "\n\n\n\n");
+    }
+
+    #[test]
+    fn test_by_pairs_first_gt_second() {
+        let iter = by_pairs("one\ntwo\nthree\nprimary\nsecondary\n", "first\nsecond\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\n"
+        );
+    }
+
+    #[test]
+    fn test_by_pairs_first_eq_second() {
+        let iter = by_pairs("one\ntwo\nthree\n", "first\nsecond\nthird\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\nthree third\n"
+        );
+    }
+
+    #[test]
+    fn test_by_pairs_first_lt_second() {
+        let iter = by_pairs("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n");
+        assert_eq!(
+            &iter.collect::<String>(),
+            "one first\ntwo second\nthree third\n"
+        );
+    }
+
+    #[test]
+    fn test_by_pairs_first_empty() {
+        let iter = by_pairs("", "first\nsecond\nthird\nfourth\nfifth\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_pairs_second_empty() {
+        let iter = by_pairs("one\ntwo\nthree\n", "");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_pairs_first_second_empty() {
+        let iter = by_pairs("", "");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_pairs_first_newline() {
+        let iter = by_pairs("\n\n", "first\nsecond\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_pairs_first_newline_gt_second() {
+        let iter = by_pairs("one\ntwo\n\n\n", "first\nsecond\n");
+        assert_eq!(&iter.collect::<String>(), "one first\ntwo second\n");
+    }
+
+    #[test]
+    fn test_by_pairs_newlines() {
+        let iter = by_pairs("\n\n\n\n", "\n\n\n\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_pairs_second_newline() {
+        let iter = by_pairs("one\ntwo\n\n\n", "\n\n");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn test_by_three_lines_first_gt_second() {
+        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_eq_second() {
+        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\n", "primary\nsecondary\ntertiary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree third tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_lt_second() {
+        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\nquaternary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree third tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_empty() {
+        let iter = by_three_lines("", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_second_empty() {
+        let iter = by_three_lines("one\ntwo\nthree\n", "", "primary\nsecondary\ntertiary\nquaternary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary\ntwo secondary\nthree tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_third_empty() {
+        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first\ntwo second\nthree third\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_newline() {
+        let iter = by_three_lines("\n\n\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "first primary\nsecond secondary\nthird tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_second_newline() {
+        let iter = by_three_lines("\n\n\n", "\n\n\n", "primary\nsecondary\ntertiary\nquaternary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "primary\nsecondary\ntertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_newlines() {
+        let iter = by_three_lines("\n\n\n\n", "\n\n\n\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "\n\n\n\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_second_third_newline() {
+        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_third_newline() {
+        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first\ntwo second\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_third_newline() {
+        let iter = by_three_lines("\n\n\n", "first\nsecond\nthird\nfourth\nfifth\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "first\nsecond\nthird\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_second_newline() {
+        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "\n\n", "primary\nsecondary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_skip_one() {
+        let iter = by_three_lines("one\n\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\nquaternary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\nsecond secondary\nthree third tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_skip_one() {
+        let iter = by_three_lines("one\n\nthree\n", "\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary\nsecond secondary\nthree third\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_first_gt_second() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary uno\ntwo second secondary due\nthree tre\nfour quattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_first_empty() {
+        let iter = by_four_lines("", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_second_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary uno\ntwo secondary due\nthree tre\nfour quattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_third_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first uno\ntwo second due\nthree tre\nfour quattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_fourth_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_second_third_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one uno\ntwo due\nthree tre\nfour quattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_second_third_fourth_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "", "");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_second_fourth_empty() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "primary\nsecondary\n", "");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_first_newline() {
+        let iter = by_four_lines("\n\n\n\n", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "first primary uno\nsecond secondary due\ntre\nquattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_first_second_newline() {
+        let iter = by_four_lines("\n\n\n\n", "\n\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "primary uno\nsecondary due\ntre\nquattro\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_first_second_third_fourth_newline() {
+        let iter = by_four_lines("\n\n\n\n", "\n\n", "\n\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "\n\n\n\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_fourth_newline() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_third_fourth_newline() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "\n\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first\ntwo second\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+ 
+    #[test]
+    fn test_cat_four_lines_second_third_fourth_newline() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_cat_four_lines_second_fourth_newline() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "primary\nsecondary\n", "\n\n\n\n");
+        let com_text = &iter.collect::<String>();
 
-/// Concatenating four texts along the lines of the first text returns an iterator.
-///
-/// - Lines are joined by whitespace.
-/// - If the first text ends, the remaining lines of the second text are ignored.
-/// - No spaces are inserted before or after empty lines.
-/// # Examples
-///
-/// ```
-/// use cattocol::by_four_lines;
-///
-/// let first_txt = "One in english,\nEin in german,\n";
-/// let second_txt = "two in english,\nzwei in german,\n";
-/// let third_txt = "three in english,\ndrei in german,\n";
-/// let fourth_txt = "four in english.\nvier in german.\n";
-/// let text = "One in english, two in english, three in english, four in english.\n\
-///     Ein in german, zwei in german, drei in german, vier in german.\n";
-/// let concatenated_txt = by_four_lines(first_txt, second_txt, third_txt, fourth_txt).collect::<String>();
-///
-/// assert_eq!(&concatenated_txt, text);
-/// ```
-#[inline]
-pub fn by_four_lines<'a>(
-    first_str: &'a str,
-    second_str: &'a str,
-    third_str: &'a str,
-    fourth_str: &'a str,
-) -> impl Iterator<Item = &'a str> {
-    let first_iter = first_str.lines();
-    let mut second_iter = second_str.lines();
-    let mut third_iter = third_str.lines();
-    let mut fourth_iter = fourth_str.lines();
+        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
 
-    first_iter.flat_map(move |first_line| {
-        let mut first_space_take = 0;
-        let mut second_space_take = 0;
-        let mut third_space_take = 0;
-        let first_line_notempty = !first_line.is_empty();
-        let mut second_line_notempty = false;
-        let mut third_line_notempty = false;
-        let mut second_line = "";
-        let mut third_line = "";
-        let mut fourth_line = "";
+        println!("{:?}", com_text);
+    }
 
-        if let Some(line) = second_iter.next() {
-            second_line_notempty = !line.is_empty();
-            if first_line_notempty && second_line_notempty {
-                first_space_take = 1;
-            };
-            second_line = line;
-        }
+    #[test]
+    fn test_cat_four_lines_first_thirdd_newline() {
+        let iter = by_four_lines("\n\n\n\n", "first\nsecond\n", "\n\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
 
-        if let Some(line) = third_iter.next() {
-            third_line_notempty = !line.is_empty();
-            if (first_line_notempty || second_line_notempty) && !line.is_empty() {
-                second_space_take = 1;
-            };
-            third_line = line;
-        }
+        assert_eq!(com_text, "first uno\nsecond due\ntre\nquattro\n");
 
-        if let Some(line) = fourth_iter.next() {
-            if (first_line_notempty || second_line_notempty || third_line_notempty)
-                && !line.is_empty()
-            {
-                third_space_take = 1;
-            };
-            fourth_line = line;
-        }
+        println!("{:?}", com_text);
+    }
 
-        iter::once(first_line)
-            .chain(
-                iter::once(" ")
-                    .take(first_space_take)
-                    .chain(second_line.lines()),
-            )
-            .chain(
-                iter::once(" ")
-                    .take(second_space_take)
-                    .chain(third_line.lines()),
-            )
-            .chain(
-                iter::once(" ")
-                    .take(third_space_take)
-                    .chain(fourth_line.lines()),
-            )
-            .chain(iter::once("\n"))
-    })
-}
+    #[test]
+    fn test_cat_four_lines_second_third() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n", "uno\ndue\ntre\nquattro\n");
+        let com_text = &iter.collect::<String>();
 
-#[inline]
-fn max_line_len(text: &str) -> usize {
-    text.lines()
-        .map(|line| line.chars().count())
-        .max()
-        .unwrap_or(0)
-}
+        assert_eq!(com_text, "one uno\ntwo due\nthree tre\nfour quattro\n");
 
-#[inline]
-fn max_line_len_no_esc(text: &str) -> usize {
-    max_line_len(std::str::from_utf8(&strip(text).unwrap()).unwrap())
-}
+        println!("{:?}", com_text);
+    }
 
-#[rustfmt::skip]
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_cat_four_lines_fourth_skip() {
+        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "uno\n\n\nquattro\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one first primary uno\ntwo second secondary\nthree\nfour quattro\n");
+
+        println!("{:?}", com_text);
+    }
 
     #[test]
-    fn cat_one_two_txt() {
-        let txt_col = "Combine two texts Returns an iterator\ninto one text from one\nfrom two columns. text of two\nmerged columns.\nCollect to String.\n";
+    fn combine_clip_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).clip_width(20);
+        let txt_col = "Combine two texts Re\ninto one text     fr\nfrom two columns. te\n                  me\n                  Co\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
         let txt_two =
             "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn cat_two_one_txt() {
-        let txt_col = "Returns an iterator Combine two texts\nfrom one into one text\ntext of two from two columns.\nmerged columns.\nCollect to String.\n";
+    fn combine_esc_clip_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).clip_width(10);
+        let txt_col = "\x1b[33mCombine\x1b[0m \x1b[36mtw\x1b[0m\ninto one t\n";
+        let txt_one = "\x1b[33mCombine\x1b[0m \x1b[36mtwo\x1b[0m texts\ninto one text";
+        let txt_two = "Returns\nfrom one";
+        let texts = cat_to_col.combine_col_esc(txt_one, txt_two).collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_wrap_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).wrap_width(10);
+        let txt_col = "Combine   Returns an iterator\ntwo texts\ninto one  from one\ntext\nfrom two  text of two\ncolumns.\n          merged columns.\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col(&txt_two, &txt_one).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+        let txt_two = "Returns an iterator\nfrom one\ntext of two\nmerged columns.";
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn cat_one_empty_txt() {
-        let txt_col = "Combine two texts\ninto one text\nfrom two columns.\n";
+    fn combine_esc_wrap_reopen_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).wrap_width(8);
+        let txt_col = "one \x1b[31mtwo\x1b[0m Returns\n\x1b[31mthree\x1b[0m\nfour\n";
+        let txt_one = "one \x1b[31mtwo three\x1b[0m four";
+        let txt_two = "Returns";
+        let texts = cat_to_col.combine_col_esc(txt_one, txt_two).collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_fixed_truncate_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "Combine Return\ninto on from o\nfrom tw text o\n        merged\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let texts = cat_to_col(&txt_one, "").collect::<String>();
-        println!("\n{txt_one}");
+        let txt_two = "Returns an iterator\nfrom one\ntext of two\nmerged columns.";
+        let texts = cat_to_col.combine_col_fixed(txt_one, txt_two, 7, 6).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn cat_one_empty_line_txt() {
-        let txt_col = "Combine two texts\n\ninto one text\nfrom two columns.\n";
-        let txt_one = "Combine two texts\n\ninto one text\nfrom two columns.";
-        let texts = cat_to_col(&txt_one, "").collect::<String>();
-        println!("\n{txt_one}");
+    fn combine_fixed_fill_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(2);
+        let txt_col = "Combine two texts...Returns...\ninto one............from one..\n....................text......\n";
+        let txt_one = "Combine two texts\ninto one";
+        let txt_two = "Returns\nfrom one\ntext";
+        let texts = cat_to_col.combine_col_fixed(txt_one, txt_two, 18, 10).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn cat_one_newline_txt() {
-        let txt_col = " Combine two texts\n into one text\nfrom two columns.\n";
-        let txt_two = "Combine two texts\ninto one text\nfrom two columns.";
-        let texts = cat_to_col("\n\n", &txt_two).collect::<String>();
-        println!("\n{txt_two}");
+    fn combine_fixed_multibyte_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "╍╍╍ äöü\nab  ü  \n";
+        let txt_one = "╍╍╍╍╍\nab";
+        let txt_two = "äöüß\nü";
+        let texts = cat_to_col.combine_col_fixed(txt_one, txt_two, 3, 3).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn cat_empty_txt() {
-        let texts = cat_to_col("", "").collect::<String>();
-        assert_eq!(texts, "");
+    fn rows_one_two_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let rows = cat_to_col.rows(txt_one, txt_two);
+        println!("\n{rows:?}");
+        assert_eq!(rows.len(), 5);
+        assert!(rows.iter().all(|row| row.0.chars().count() == 17));
+        assert_eq!(rows[1], ("into one text    ".to_string(), "from one".to_string()));
+        assert_eq!(rows[4], ("                 ".to_string(), "Collect to String.".to_string()));
     }
 
     #[test]
-    fn cat_one_space_txt() {
-        let txt_col = "Combine two texts  \ninto one text  \nfrom two columns.  \n";
+    fn rows_two_one_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let texts = cat_to_col(&txt_one, " \n \n ").collect::<String>();
-        println!("\n{txt_one}");
+        let txt_two = "Returns an iterator\nfrom one";
+        let rows = cat_to_col.rows(txt_one, txt_two);
+        println!("\n{rows:?}");
+        assert!(rows.iter().all(|row| row.0.chars().count() == 17));
+        assert_eq!(rows[2], ("from two columns.".to_string(), String::new()));
+    }
+
+    #[test]
+    fn validate_default() {
+        assert_eq!(CatToCol::new().fill('╍').repeat(3).wrap_width(1).validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_control_fill() {
+        assert_eq!(CatToCol::new().fill('\n').validate(), Err(CatToColError::ControlFill));
+        assert_eq!(CatToCol::new().fill('\t').validate(), Err(CatToColError::ControlFill));
+    }
+
+    #[test]
+    fn validate_zero_wrap_width() {
+        let cat_to_col = CatToCol::new().wrap_width(0);
+        assert_eq!(cat_to_col.validate(), Err(CatToColError::ZeroWrapWidth));
+        assert_eq!(cat_to_col.validate().unwrap_err().to_string(), "the wrap width is zero");
+    }
+
+    #[test]
+    fn validate_zero_tab_stop() {
+        let cat_to_col = CatToCol::new().tab_align(0);
+        assert_eq!(cat_to_col.validate(), Err(CatToColError::ZeroTabStop));
+        assert_eq!(cat_to_col.validate().unwrap_err().to_string(), "the tab stop is zero");
+        assert_eq!(CatToCol::new().normalize_leading_whitespace(0).validate(), Err(CatToColError::ZeroTabStop));
+        assert_eq!(CatToCol::new().tab_align(8).validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_zero_tab_stops() {
+        assert_eq!(CatToCol::new().tab_stops(0).validate(), Err(CatToColError::ZeroTabStop));
+        assert_eq!(CatToCol::new().tab_stops(8).validate(), Ok(()));
+    }
+
+    #[test]
+    fn try_combine_col_esc_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.try_combine_col_esc("\x1b[31mone\x1b[0m\nthree", "1\n3").unwrap().collect::<String>();
+        assert_eq!(texts, "\x1b[31mone\x1b[0m   1\nthree 3\n");
+        let texts = cat_to_col.try_combine_col_esc("\x1b[3", "\x1b]8;;").unwrap().collect::<String>();
+        assert_eq!(texts, cat_to_col.combine_col_esc("\x1b[3", "\x1b]8;;").collect::<String>());
+        assert!(matches!(cat_to_col.wrap_width(0).try_combine_col_esc("one", "1"), Err(CatToColError::ZeroWrapWidth)));
+    }
+
+    #[test]
+    fn test_interleave_lines_first_eq_second() {
+        let iter = interleave_lines("one\nthree\nfive\n", "two\nfour\nsix\n");
+        assert_eq!(&iter.collect::<String>(), "one\ntwo\nthree\nfour\nfive\nsix\n");
+    }
+
+    #[test]
+    fn test_interleave_lines_first_gt_second() {
+        let iter = interleave_lines("one\nthree\nfive\nsix\n", "two\n");
+        assert_eq!(&iter.collect::<String>(), "one\ntwo\nthree\nfive\nsix\n");
+    }
+
+    #[test]
+    fn test_interleave_lines_first_lt_second() {
+        let iter = interleave_lines("one\n", "two\nthree\nfour\n");
+        assert_eq!(&iter.collect::<String>(), "one\ntwo\nthree\nfour\n");
+    }
+
+    #[test]
+    fn test_interleave_lines_newlines() {
+        let iter = interleave_lines("one\n\nthree\n", "\ntwo\n");
+        assert_eq!(&iter.collect::<String>(), "one\n\n\ntwo\nthree\n");
+    }
+
+    #[test]
+    fn test_interleave_lines_empty() {
+        let iter = interleave_lines("", "");
+        assert_eq!(&iter.collect::<String>(), "");
+    }
+
+    #[test]
+    fn combine_prefix_suffix_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(3).row_prefix("> ").row_suffix(" <");
+        let txt_col = "> left   right <\n> l      r <\n>        tail <\n";
+        let texts = cat_to_col.combine_col("left\nl", "right\nr\ntail").collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_prefix_left_tail_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).row_prefix("> ");
+        let txt_col = "> left right\n> l\n";
+        let texts = cat_to_col.combine_col("left\nl", "right").collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
+
+    #[test]
+    fn combine_fixed_prefix_suffix_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).row_prefix("| ").row_suffix(" |");
+        let txt_col = "| lef rig |\n|     r   |\n";
+        let texts = cat_to_col.combine_col_fixed("left", "right\nr", 3, 3).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn combine_one_two_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
-        let txt_col = "Combine two texts Returns an iterator\ninto one text     from one\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
+    fn test_truncate_to_width_ascii() {
+        assert_eq!(truncate_to_width("Combine two texts", 10, "..."), "Combine...");
+        assert_eq!(truncate_to_width("Combine two texts", 17, "..."), "Combine two texts");
+        assert_eq!(truncate_to_width("Combine two texts", 7, ""), "Combine");
+        assert_eq!(truncate_to_width("Combine two texts", 2, "..."), "..");
+    }
+
+    #[test]
+    fn test_truncate_to_width_cjk() {
+        assert_eq!(truncate_to_width("二つのテキスト", 7, "…"), "二つの…");
+        assert_eq!(truncate_to_width("二つのテキスト", 6, "…"), "二つ…");
+        assert_eq!(truncate_to_width("二つのテキスト", 5, ""), "二つ");
+    }
+
+    #[test]
+    fn test_truncate_to_width_emoji() {
+        assert_eq!(truncate_to_width("👍🏽👍🏽👍🏽", 5, "…"), "👍🏽👍🏽…");
+        assert_eq!(truncate_to_width("👩‍👩‍👧 family", 3, "…"), "👩‍👩‍👧…");
+        assert_eq!(truncate_to_width("e\u{301}e\u{301}e\u{301}", 2, ""), "e\u{301}e\u{301}");
+    }
+
+    #[test]
+    fn test_by_three_lines_first_empty_second_third() {
+        let iter = by_three_lines("\n\none\n", "first\n\nsecond\n", "primary\nsecondary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "first primary\nsecondary\none second\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_third_second_empty() {
+        let iter = by_three_lines("one\ntwo\nthree\n", "\n\nthird\n", "primary\n\ntertiary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "one primary\ntwo\nthree third tertiary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_three_lines_first_second_empty_third() {
+        let iter = by_three_lines("\n\n", "\n", "primary\nsecondary\n");
+        let com_text = &iter.collect::<String>();
+
+        assert_eq!(com_text, "primary\nsecondary\n");
+
+        println!("{:?}", com_text);
+    }
+
+    #[test]
+    fn test_by_lines_reported_first_lt_second() {
+        let (iter, report) = by_lines_reported("one\ntwo\n", "first\nsecond\nthird\nfourth\nfifth\n");
+        assert_eq!(&iter.collect::<String>(), "one first\ntwo second\n");
+        assert_eq!(report, LinesReport { dropped: 3, unpaired: 0 });
+    }
+
+    #[test]
+    fn test_by_lines_reported_first_gt_second() {
+        let (iter, report) = by_lines_reported("one\ntwo\nthree\nprimary\nsecondary\n", "first\nsecond\n");
+        assert_eq!(&iter.collect::<String>(), "one first\ntwo second\nthree\nprimary\nsecondary\n");
+        assert_eq!(report, LinesReport { dropped: 0, unpaired: 3 });
+    }
+
+    #[test]
+    fn test_by_lines_reported_first_eq_second() {
+        let (_, report) = by_lines_reported("one\ntwo\n", "first\nsecond\n");
+        assert_eq!(report, LinesReport::default());
+    }
+
+    #[test]
+    fn combine_empty_cell_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).empty_cell("—");
+        let txt_col = "Combine two texts Returns an iterator\ninto one text     —\nfrom two columns. —\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+        let txt_two = "Returns an iterator\n";
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn combine_one_newline_two_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
-        let txt_col = "                  Returns an iterator\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
-        let txt_one = "\nfrom two columns.\n\n";
-        let txt_two =
-            "Returns an iterator\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+    fn combine_empty_left_cell_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).empty_left_cell("—");
+        let txt_col = "one Returns an iterator\n—   from one\n—   text of two\n";
+        let txt_one = "one\n";
+        let txt_two = "Returns an iterator\nfrom one\ntext of two";
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn combine_two_one_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
-        let txt_col = "Returns an iterator Combine two texts\nfrom one            into one text\ntext of two         from two columns.\nmerged columns.\nCollect to String.\n";
-        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_two, &txt_one).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+    fn combine_empty_cell_width_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).empty_left_cell("(none)").empty_cell("-");
+        let txt_col = "one    first\n(none) -\n(none) third\n";
+        let texts = cat_to_col.combine_col("one\n", "first\n\nthird").collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn combine_one_two_repeat_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(10);
-        let txt_col = "Combine two texts          Returns an iterator\ninto one text              from one\nfrom two columns.          text of two\n                           merged columns.\n                           Collect to String.\n";
-        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+    fn combine_fixed_empty_cell_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).empty_cell("—");
+        let txt_col = "one —  \ntwo —  \n";
+        let texts = cat_to_col.combine_col_fixed("one\ntwo", "", 3, 3).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
+    #[derive(Default)]
+    struct CountWriter {
+        bytes: Vec<u8>,
+        writes: usize,
+    }
+
+    impl Write for CountWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.bytes.extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+            self.writes += 1;
+            self.write(buf).map(|_| ())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
     #[test]
-    fn combine_one_empty_repeat_txt() {
+    fn write_combine_one_two_txt() {
         let cat_to_col = CatToCol::new().fill(' ').repeat(10);
-        let txt_col = "Combine two texts\ninto one text\nfrom two columns.\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let texts = cat_to_col.combine_col(&txt_one, "").collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{texts}");
-        assert_eq!(texts, txt_col);
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let mut writer = CountWriter::default();
+        cat_to_col.write_combine_col(&mut writer, txt_one, txt_two).unwrap();
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(writer.bytes, texts.as_bytes());
+        assert_eq!(writer.writes, 1);
     }
 
     #[test]
-    fn combine_empty_one_repeat_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(10);
-        let txt_col = "          Combine two texts\n          into one text\n          from two columns.\n";
-        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let texts = cat_to_col.combine_col("", &txt_one).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{texts}");
-        assert_eq!(texts, txt_col);
+    fn write_combine_esc_large_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_one = "\x1b[33mCombine\x1b[0m two texts\n".repeat(1000);
+        let txt_two = "Returns an iterator\n".repeat(1000);
+        let mut writer = CountWriter::default();
+        cat_to_col.write_combine_col_esc(&mut writer, &txt_one, &txt_two).unwrap();
+        let texts = cat_to_col.combine_col_esc(&txt_one, &txt_two).collect::<String>();
+        let fragments = cat_to_col.combine_col_esc(&txt_one, &txt_two).count();
+        assert_eq!(writer.bytes, texts.as_bytes());
+        assert_eq!(writer.writes, texts.len().div_ceil(WRITE_BUF_LEN));
+        assert!(writer.writes * 100 < fragments);
     }
 
     #[test]
-    fn combine_one_two_repeat_fill_txt() {
-        let cat_to_col = CatToCol::new().repeat(10).fill('╍');
-        let txt_col = "Combine two texts╍╍╍╍╍╍╍╍╍╍Returns an iterator\ninto one text╍╍╍╍╍╍╍╍╍╍╍╍╍╍from one\nfrom two columns.╍╍╍╍╍╍╍╍╍╍text of two\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍merged columns.\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍Collect to String.\n";
+    fn combine_rtl_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).direction(Direction::Rtl);
+        let txt_col = "Returns an iterator Combine two texts\nfrom one            into one text\ntext of two         from two columns.\nmerged columns.\nCollect to String.\n";
         let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
         let txt_two =
             "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
+        for (row, line_one) in texts.lines().zip(txt_one.lines()) {
+            assert!(row.ends_with(line_one));
+        }
     }
 
     #[test]
-    fn combine_one_two_repeat_zero_fill_txt() {
-        let cat_to_col = CatToCol::new().repeat(0).fill('╍');
-        let txt_col = "Combine two textsReturns an iterator\ninto one text╍╍╍╍from one\nfrom two columns.text of two\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍merged columns.\n╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍╍Collect to String.\n";
-        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
-        println!("\n{txt_col}");
+    fn combine_fixed_rtl_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).direction(Direction::Rtl);
+        let txt_col = "Retu Combin\n     into  \n";
+        let texts = cat_to_col.combine_col_fixed("Combine\ninto", "Returns", 6, 4).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn combine_esc_one_two_txt() {
-        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
-        let txt_col = "\x1b[33mCombine\x1b[0m \x1b[36mtwo\x1b[0m texts Returns an iterator\ninto one text     from one\nfrom two columns. text of two\n                  merged columns.\n                  Collect to String.\n";
-        let txt_one = "\x1b[33mCombine\x1b[0m \x1b[36mtwo\x1b[0m texts\ninto one text\nfrom two columns.";
-        let txt_two =
-            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
-        let texts = cat_to_col.combine_col_esc(&txt_one, &txt_two).collect::<String>();
-        println!("\n{txt_one}");
-        println!("\n{txt_two}");
+    fn left_column_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let txt_col = "Combine two texts\ninto one text....\n.................\nfrom two columns.\n";
+        let txt_one = "Combine two texts\ninto one text\n\nfrom two columns.";
+        let texts = cat_to_col.left_column(txt_one).collect::<String>();
         println!("\n{texts}");
         assert_eq!(texts, txt_col);
+        assert!(texts.lines().all(|line| line.chars().count() == 17));
     }
 
     #[test]
-    fn test_by_lines_first_gt_second() {
-        let iter = by_lines("one\ntwo\nthree\nprimary\nsecondary\n", "first\nsecond\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\nthree\nprimary\nsecondary\n"
-        );
+    fn left_column_empty_txt() {
+        let cat_to_col = CatToCol::new();
+        assert_eq!(cat_to_col.left_column("").collect::<String>(), "");
     }
 
     #[test]
-    fn test_by_lines_first_eq_second() {
-        let iter = by_lines("one\ntwo\nthree\n", "first\nsecond\nthird\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\nthree third\n"
-        );
+    fn combine_cols_empty_slice() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        assert_eq!(cat_to_col.combine_cols(&[]).count(), 0);
+        assert_eq!(cat_to_col.combine_cols(&["", ""]).count(), 0);
     }
 
     #[test]
-    fn test_by_lines_first_lt_second() {
-        let iter = by_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\nthree third\n"
-        );
+    fn combine_cols_single_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let texts = cat_to_col.combine_cols(&["one\n\nthree"]).collect::<String>();
+        assert_eq!(texts, "one\n\nthree\n");
     }
 
     #[test]
-    fn test_by_lines_first_empty() {
-        let iter = by_lines("", "first\nsecond\nthird\nfourth\nfifth\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_cols_three_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let txt_col = "one   first  primary\ntwo   second \nthree        tertiary\n";
+        let texts = cat_to_col
+            .combine_cols(&["one\ntwo\nthree", "first\nsecond", "primary\n\ntertiary"])
+            .collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn test_by_lines_second_empty() {
-        let iter = by_lines("one\ntwo\nthree\n", "");
-        assert_eq!(&iter.collect::<String>(), "one\ntwo\nthree\n");
+    fn combine_cols_two_eq_combine_col_txt() {
+        let cat_to_col = CatToCol::new().fill('╍').repeat(3);
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        assert_eq!(
+            cat_to_col.combine_cols(&[txt_one, txt_two]).collect::<String>(),
+            cat_to_col.combine_col(txt_one, txt_two).collect::<String>()
+        );
     }
 
     #[test]
-    fn test_by_lines_first_second_empty() {
-        let iter = by_lines("", "");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_fill_str_txt() {
+        let cat_to_col = CatToCol::new().fill_str("e\u{301}").repeat(1);
+        let txt_col = "onee\u{301}e\u{301}e\u{301}first\nthreee\u{301}second\n";
+        let texts = cat_to_col.combine_col("one\nthree", "first\nsecond").collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+        assert_eq!(cat_to_col.validate(), Ok(()));
     }
 
     #[test]
-    fn test_by_lines_first_second_newline() {
-        let iter = by_lines("\n", "\n");
-        assert_eq!(&iter.collect::<String>(), "\n");
+    fn validate_fill_width() {
+        assert_eq!(CatToCol::new().fill_str("→").validate(), Ok(()));
+        assert_eq!(CatToCol::new().fill_str("").validate(), Err(CatToColError::FillWidth));
+        assert_eq!(CatToCol::new().fill_str("->").validate(), Err(CatToColError::FillWidth));
+        assert_eq!(CatToCol::new().fill('字').validate(), Err(CatToColError::FillWidth));
     }
 
     #[test]
-    fn test_by_lines_first_empty_second_newline() {
-        let iter = by_lines("", "\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_esc_zebra_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).zebra(Some(("\x1b[47m", "\x1b[100m")));
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two =
+            "Returns an iterator\nfrom one\ntext of two\nmerged columns.\nCollect to String.";
+        let texts = cat_to_col.combine_col_esc(txt_one, txt_two).collect::<String>();
+        let plain = CatToCol::new().fill(' ').repeat(1).combine_col(txt_one, txt_two).collect::<String>();
+        println!("\n{texts}");
+        for (index, (row, plain_row)) in texts.lines().zip(plain.lines()).enumerate() {
+            let style = if index % 2 == 0 { "\x1b[47m" } else { "\x1b[100m" };
+            assert_eq!(row, format!("{style}{plain_row}\x1b[0m"));
+        }
+        assert_eq!(texts.lines().count(), 5);
     }
 
     #[test]
-    fn test_by_lines_first_newline_second_empty() {
-        let iter = by_lines("\n", "");
-        assert_eq!(&iter.collect::<String>(), "\n");
+    fn combine_zebra_plain_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1).zebra(Some(("\x1b[47m", "\x1b[100m")));
+        let texts = cat_to_col.combine_col("one\ntwo", "first\nsecond").collect::<String>();
+        assert_eq!(texts, "one first\ntwo second\n");
     }
 
     #[test]
-    fn test_by_lines_first_two_newline_second_empty() {
-        let iter = by_lines("one\n\ntwo\n\nthree\n\n", "");
-        assert_eq!(&iter.collect::<String>(), "one\n\ntwo\n\nthree\n\n");
+    fn getters_default() {
+        let cat_to_col = CatToCol::default();
+        assert_eq!(cat_to_col.get_fill(), " ");
+        assert_eq!(cat_to_col.get_repeat(), 0);
     }
 
     #[test]
-    fn test_by_lines_first_two_newline() {
-        let iter = by_lines("one\n\ntwo\n\nthree\n\n", "first\nsecond\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\nsecond\ntwo\n\nthree\n\n"
-        );
+    fn getters_fill_repeat() {
+        let cat_to_col = CatToCol::new().fill('╍').repeat(10);
+        assert_eq!(cat_to_col.get_fill(), "╍");
+        assert_eq!(cat_to_col.get_repeat(), 10);
+        assert_eq!(cat_to_col.fill_str("e\u{301}").get_fill(), "e\u{301}");
     }
 
-    #[test]
-    fn test_by_lines_first_second_two_newline() {
-        let iter = by_lines("one\ntwo\n\nfour\n\nsix\n", "first\n\nthird\nfourth\n\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo\nthird\nfour fourth\n\nsix\n"
-        );
-    }
+    static CAT_TO_COL: CatToCol = CatToCol::new();
 
     #[test]
-    fn test_by_lines_first_newline() {
-        let iter = by_lines("\n\n", "first\nsecond\n");
-        assert_eq!(&iter.collect::<String>(), "first\nsecond\n");
+    fn static_combine_txt() {
+        let texts = CAT_TO_COL.combine_col("one\ntwo", "first\nsecond").collect::<String>();
+        assert_eq!(texts, "onefirst\ntwosecond\n");
+        assert_eq!(CAT_TO_COL, CatToCol::default());
     }
 
     #[test]
-    fn test_by_lines_first_newline_gt_second() {
-        let iter = by_lines("\n\n\n\n", "first\nsecond\n");
-        assert_eq!(&iter.collect::<String>(), "first\nsecond\n\n\n");
+    fn hash_set_insert() {
+        use std::collections::HashSet;
+
+        let mut configs = HashSet::new();
+        assert!(configs.insert(CatToCol::new().fill('╍').repeat(3)));
+        assert!(configs.insert(CatToCol::new().repeat(3)));
+        assert!(!configs.insert(CatToCol::new().repeat(3).fill('╍')));
+        assert!(!configs.insert(CatToCol::new().fill(' ').repeat(3)));
+        assert_eq!(configs.len(), 2);
     }
 
     #[test]
-    fn test_by_lines_newlines() {
-        let iter = by_lines("\n\n\n\n", "\n\n\n\n");
-        assert_eq!(&iter.collect::<String>(), "\n\n\n\n");
+    fn combine_map_uppercase_txt() {
+        let cat_to_col = CatToCol::new().fill(' ');
+        let txt_col = "Combine two texts | RETURNS AN ITERATOR\ninto one text     | FROM ONE\nfrom two columns. | \n";
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two = "Returns an iterator\nfrom one";
+        let texts = cat_to_col
+            .combine_col_map(txt_one, txt_two, |left, right| {
+                format!("{left} | {}", right.to_uppercase())
+            })
+            .collect::<String>();
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn test_by_pairs_first_gt_second() {
-        let iter = by_pairs("one\ntwo\nthree\nprimary\nsecondary\n", "first\nsecond\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\n"
-        );
+    fn combine_ambiguous_wide() {
+        let txt_one = "①②③\nabc";
+        let txt_two = "one\ntwo";
+        let narrow = CatToCol::new().repeat(1).combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(narrow, "①②③ one\nabc two\n");
+        let cat_to_col = CatToCol::new().repeat(1).ambiguous_wide(true);
+        let wide = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(wide, "①②③ one\nabc    two\n");
+        let wide_esc = cat_to_col.combine_col_esc("\x1b[31m①②③\x1b[0m\nabc", txt_two).collect::<String>();
+        assert_eq!(wide_esc, "\x1b[31m①②③\x1b[0m one\nabc    two\n");
     }
 
     #[test]
-    fn test_by_pairs_first_eq_second() {
-        let iter = by_pairs("one\ntwo\nthree\n", "first\nsecond\nthird\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\nthree third\n"
-        );
+    fn combine_width_mode() {
+        let txt_one = "字字\nabc";
+        let txt_two = "one\ntwo";
+        let unicode = CatToCol::new().repeat(1).combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(unicode, "字字 one\nabc  two\n");
+        let chars = CatToCol::new().repeat(1).width_mode(WidthMode::Chars).ambiguous_wide(true);
+        assert_eq!(chars.combine_col(txt_one, txt_two).collect::<String>(), "字字  one\nabc two\n");
     }
 
     #[test]
-    fn test_by_pairs_first_lt_second() {
-        let iter = by_pairs("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n");
-        assert_eq!(
-            &iter.collect::<String>(),
-            "one first\ntwo second\nthree third\n"
-        );
+    fn combine_width_mode_bytes() {
+        let txt_one = "one\nthree\n\tfive";
+        let txt_two = "1\n3\n5\n7";
+        let chars = CatToCol::new().repeat(1).width_mode(WidthMode::Chars).wrap_width(4);
+        let bytes = chars.clone().width_mode(WidthMode::Bytes);
+        assert_eq!(bytes.combine_col(txt_one, txt_two).collect::<String>(), chars.combine_col(txt_one, txt_two).collect::<String>());
+        assert_eq!(bytes.wrap_width(10).combine_col("äö\na", "1\n2").collect::<String>(), "äö 1\na    2\n");
     }
 
-    #[test]
-    fn test_by_pairs_first_empty() {
-        let iter = by_pairs("", "first\nsecond\nthird\nfourth\nfifth\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    /// Checks that every fragment is a terminator or has none and counts the rows.
+    fn assert_rows<'a>(fragments: impl Iterator<Item = &'a str>, rows: usize) {
+        let mut text = String::new();
+        for fragment in fragments {
+            assert!(fragment == "\n" || !fragment.contains('\n'), "{fragment:?}");
+            text.push_str(fragment);
+        }
+        assert!(text.is_empty() || text.ends_with('\n'));
+        assert_eq!(text.split_terminator('\n').count(), rows);
     }
 
-    #[test]
-    fn test_by_pairs_second_empty() {
-        let iter = by_pairs("one\ntwo\nthree\n", "");
-        assert_eq!(&iter.collect::<String>(), "");
+    proptest! {
+        #[test]
+        fn prop_combine_col_rows(txt_one in "[a \n\r字]{0,24}", txt_two in "[b \n\r①]{0,24}") {
+            let rows = max(txt_one.lines().count(), txt_two.lines().count());
+            let cat_to_col = CatToCol::new().repeat(1);
+            assert_rows(cat_to_col.combine_col(&txt_one, &txt_two), rows);
+            assert_rows(cat_to_col.combine_col_esc(&txt_one, &txt_two), rows);
+            assert_rows(cat_to_col.direction(Direction::Rtl).combine_col(&txt_one, &txt_two), rows);
+        }
+
+        #[test]
+        fn prop_combine_col_esc_rows(txt_one in "(\x1b\\[3[0-7]m|[a \n])*", txt_two in "(\x1b\\[0m|[b\n])*") {
+            let rows = max(txt_one.lines().count(), txt_two.lines().count());
+            let cat_to_col = CatToCol::new().zebra(Some(("\x1b[47m", "\x1b[100m")));
+            assert_rows(cat_to_col.combine_col_esc(&txt_one, &txt_two), rows);
+        }
     }
 
     #[test]
-    fn test_by_pairs_first_second_empty() {
-        let iter = by_pairs("", "");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_tab_align() {
+        let cat_to_col = CatToCol::new().repeat(3).tab_align(8);
+        let texts = cat_to_col.combine_col("one\nthree\nforty-two\n", "1\n3\n42\n").collect::<String>();
+        assert_eq!(texts, "one\t\t1\nthree\t\t3\nforty-two\t42\n");
+        let texts = cat_to_col.clone().row_prefix("> ").combine_col("one\nsix", "1\n6").collect::<String>();
+        assert_eq!(texts, "> one\t1\n> six\t6\n");
+        assert_eq!(cat_to_col.tab_align(0).validate(), Err(CatToColError::ZeroTabStop));
     }
 
     #[test]
-    fn test_by_pairs_first_newline() {
-        let iter = by_pairs("\n\n", "first\nsecond\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn cat_to_col_trailing_newline() {
+        for txt_one in ["one\ntwo\nthree", "one\ntwo\nthree\n"] {
+            for txt_two in ["1", "1\n"] {
+                assert_eq!(cat_to_col(txt_one, txt_two).collect::<String>(), "one 1\ntwo\nthree\n");
+                assert_eq!(cat_to_col(txt_two, txt_one).collect::<String>(), "1 one\ntwo\nthree\n");
+            }
+        }
     }
 
     #[test]
-    fn test_by_pairs_first_newline_gt_second() {
-        let iter = by_pairs("one\ntwo\n\n\n", "first\nsecond\n");
-        assert_eq!(&iter.collect::<String>(), "one first\ntwo second\n");
+    fn combine_boxed_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).boxed(true);
+        let texts = cat_to_col.combine_col("one\nthree", "1\n33").collect::<String>();
+        let txt_col = "┌──────┬──┐\n│one   │1 │\n│three │33│\n└──────┴──┘\n";
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+        assert_eq!(cat_to_col.combine_col("", "").count(), 0);
     }
 
     #[test]
-    fn test_by_pairs_newlines() {
-        let iter = by_pairs("\n\n\n\n", "\n\n\n\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_esc_boxed_txt() {
+        let cat_to_col = CatToCol::new().boxed(true).zebra(Some(("\x1b[47m", "\x1b[100m")));
+        let texts = cat_to_col.combine_col_esc("\x1b[31mone\nsix", "1\n6").collect::<String>();
+        let txt_col = "┌───┬─┐\n│\x1b[47m\x1b[31mone\x1b[0m│\x1b[47m1\x1b[0m│\n\
+            │\x1b[100msix\x1b[0m│\x1b[100m6\x1b[0m│\n└───┴─┘\n";
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
     }
 
     #[test]
-    fn test_by_pairs_second_newline() {
-        let iter = by_pairs("one\ntwo\n\n\n", "\n\n");
-        assert_eq!(&iter.collect::<String>(), "");
+    fn combine_symmetric_gap_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(2);
+        let one_sided = cat_to_col.combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(one_sided, "one....1\nthree..3\nfive\n");
+        let symmetric = cat_to_col.clone().symmetric_gap(true);
+        let texts = symmetric.combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(texts, "one......1\nthree....3\nfive\n");
+        let texts = symmetric.boxed(true).combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(texts, "┌───────┬───┐\n│one....│..1│\n│three..│..3│\n│five...│...│\n└───────┴───┘\n");
     }
 
     #[test]
-    fn test_by_three_lines_first_gt_second() {
-        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
-
-        println!("{:?}", com_text);
+    fn by_columns_sep_three_txt() {
+        let texts = ["One season\nDecembre,\n\nIt's cold.\n", "a year\n\n\n", "is winter.\nFebruary.\nMarch.\n"];
+        let concatenated_txt = by_columns_sep(&texts, &[" | ", " - "]).collect::<String>();
+        assert_eq!(&concatenated_txt, "One season | a year - is winter.\nDecembre, - February.\nMarch.\nIt's cold.\n");
+        let spaced_txt = by_columns_sep(&texts, &[]).collect::<String>();
+        assert_eq!(spaced_txt, by_three_lines(texts[0], texts[1], texts[2]).collect::<String>());
+        assert_eq!(by_columns_sep(&[], &[]).count(), 0);
     }
 
     #[test]
-    fn test_by_three_lines_first_eq_second() {
-        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\n", "primary\nsecondary\ntertiary\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree third tertiary\n");
-
-        println!("{:?}", com_text);
+    fn combine_esc_hyperlink_txt() {
+        let link = "\x1b]8;;https://example.com\x1b\\docs\x1b]8;;\x1b\\";
+        let bel_link = "\x1b]8;;https://example.com\x07docs\x1b]8;;\x07";
+        let cat_to_col = CatToCol::new().repeat(1);
+        for link in [link, bel_link] {
+            let txt_one = format!("{link}\nmanual");
+            let texts = cat_to_col.combine_col_esc(&txt_one, "1\n2").collect::<String>();
+            assert_eq!(texts, format!("{link}   1\nmanual 2\n"));
+        }
     }
 
     #[test]
-    fn test_by_three_lines_first_lt_second() {
-        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\nquaternary\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree third tertiary\n");
-
-        println!("{:?}", com_text);
+    fn combine_compact_tail_txt() {
+        let cat_to_col = CatToCol::new().repeat(2).symmetric_gap(true);
+        let padded = cat_to_col.combine_col("one\nthree", "1\n3\n5\n7").collect::<String>();
+        assert_eq!(padded, "one      1\nthree    3\n         5\n         7\n");
+        let compact = cat_to_col.compact_tail(true).combine_col("one\nthree", "1\n3\n5\n7").collect::<String>();
+        assert_eq!(compact, "one      1\nthree    3\n5\n7\n");
     }
 
     #[test]
-    fn test_by_three_lines_first_empty() {
-        let iter = by_three_lines("", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "");
-
-        println!("{:?}", com_text);
+    fn annotate_lines_txt() {
+        let concatenated_txt = annotate_lines("one\ntwo\nthree\n", "// TODO").collect::<String>();
+        assert_eq!(&concatenated_txt, "one // TODO\ntwo // TODO\nthree // TODO\n");
+        assert_eq!(&annotate_lines("one\ntwo", "").collect::<String>(), "one\ntwo\n");
+        assert_eq!(annotate_lines("", "// TODO").count(), 0);
     }
 
     #[test]
-    fn test_by_three_lines_second_empty() {
-        let iter = by_three_lines("one\ntwo\nthree\n", "", "primary\nsecondary\ntertiary\nquaternary\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "one primary\ntwo secondary\nthree tertiary\n");
+    fn combine_ext_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let txt_col = "one...1\nthree.3\n";
+        assert_eq!("one\nthree".combine_col_with("1\n3", &cat_to_col), txt_col);
+        assert_eq!(String::from("one\nthree").combine_col_with("1\n3", &cat_to_col), txt_col);
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_wrap_row_height_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).wrap_width(5);
+        let texts = cat_to_col.combine_col("one two three\nfour", "1\n4").collect::<String>();
+        assert_eq!(texts, "one   1\ntwo\nthree\nfour  4\n");
+        let texts = cat_to_col.min_row_height(2).combine_col("one two three\nfour", "1\n4").collect::<String>();
+        assert_eq!(texts, "one   1\ntwo\nthree\nfour  4\n\n");
     }
 
     #[test]
-    fn test_by_three_lines_third_empty() {
-        let iter = by_three_lines("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "");
-        let com_text = &iter.collect::<String>();
+    fn combine_cols_wrap_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).wrap_width(5);
+        let texts = cat_to_col.combine_cols(&["one two three\nfour", "1\n4", "I II\nIV"]).collect::<String>();
+        assert_eq!(texts, "one...1.I II\ntwo.....\nthree...\nfour..4.IV\n");
+        let texts = cat_to_col.min_row_height(2).combine_cols(&["one", "1", "I"]).collect::<String>();
+        assert_eq!(texts, "one.1.I\n......\n");
+    }
 
-        assert_eq!(com_text, "one first\ntwo second\nthree third\n");
+    #[test]
+    fn plan_combined_width() {
+        let texts = ["one\nthree", "1\n33", "I\nIII two"];
+        let cat_to_col = CatToCol::new().repeat(2);
+        let widths = cat_to_col.plan(&texts);
+        assert_eq!(widths, vec![5, 2, 7]);
+        let combined_width = cat_to_col.combined_width(&texts);
+        assert_eq!(widths.iter().sum::<usize>() + 2 * 2, combined_width);
+        let texts_col = cat_to_col.combine_cols(&texts).collect::<String>();
+        assert_eq!(texts_col.lines().map(str::len).max(), Some(combined_width));
+        assert_eq!(cat_to_col.wrap_width(4).plan(&texts), vec![4, 2, 3]);
+        assert_eq!(CatToCol::new().combined_width(&[]), 0);
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_cow_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(20);
+        let txt_one = "Combine two texts\ninto one text\nfrom two columns.";
+        let txt_two = "Returns an iterator\nfrom one\n.\n";
+        let texts = cat_to_col.combine_col_cow(txt_one, txt_two).collect::<Vec<_>>();
+        assert_eq!(texts.concat(), cat_to_col.combine_col(txt_one, txt_two).collect::<String>());
+        assert!(texts.len() < cat_to_col.combine_col(txt_one, txt_two).count() / 4);
+        assert!(texts.iter().any(|text| matches!(text, Cow::Borrowed("."))));
     }
 
     #[test]
-    fn test_by_three_lines_first_newline() {
-        let iter = by_three_lines("\n\n\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_fill_fn_txt() {
+        let cat_to_col = CatToCol::new().repeat(2);
+        let fill_fn = |index| if index % 2 == 0 { '.' } else { ' ' };
+        let texts = cat_to_col
+            .combine_col_fill_fn("one\nthree\nfive\nseven", "1\n3\n5\n7", fill_fn)
+            .collect::<String>();
+        assert_eq!(texts, "one....1\nthree  3\nfive...5\nseven  7\n");
+        let cat_to_col = CatToCol::new().fill_str("→").repeat(1);
+        let texts = cat_to_col.combine_col_fill_fn("one\nthree", "1\n3", fill_fn).collect::<String>();
+        assert_eq!(texts, "one...1\nthree 3\n");
+    }
 
-        assert_eq!(com_text, "first primary\nsecond secondary\nthird tertiary\n");
+    #[test]
+    fn combine_fill_fn_space_fill_txt() {
+        let cat_to_col = CatToCol::new().fill(' ').repeat(1);
+        let texts = cat_to_col.combine_col_fill_fn("one\nthree", "1\n3", |_| '.').collect::<String>();
+        assert_eq!(texts, "one...1\nthree.3\n");
+        let texts = cat_to_col.combine_col_cow(" ", " \nx").collect::<Vec<_>>();
+        assert_eq!(texts, [Cow::Borrowed(""), Cow::Borrowed(" "), Cow::Borrowed(" "), Cow::Borrowed(" "), Cow::Borrowed(""), Cow::Borrowed("\n"), Cow::Borrowed(""), Cow::Owned("  ".to_string()), Cow::Borrowed("x"), Cow::Borrowed(""), Cow::Borrowed("\n")]);
+        let texts = cat_to_col.combine_col_fill_fn(" ", " \nx", |_| '.').collect::<String>();
+        assert_eq!(texts, " . \n..x\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_cow_clip_separator_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).clip_width(5).row_separator("\r\n");
+        let texts = cat_to_col.combine_col_cow("one\nthree", "1\n3").collect::<String>();
+        assert_eq!(texts, "one  \r\nthree\n");
+        assert_eq!(texts, cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>());
+        let texts = cat_to_col.combine_col_fill_fn("one\nthree", "1\n3", |_| '.').collect::<String>();
+        assert_eq!(texts, "one..\r\nthree\n");
     }
 
     #[test]
-    fn test_by_three_lines_first_second_newline() {
-        let iter = by_three_lines("\n\n\n", "\n\n\n", "primary\nsecondary\ntertiary\nquaternary\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_esc_wide_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let txt_one = "\x1b[32m漢字\x1b[0m\nkanji\n\x1b[1;31m字\x1b[0m";
+        let texts = cat_to_col.combine_col_esc(txt_one, "1\n2\n3").collect::<String>();
+        let txt_col = "\x1b[32m漢字\x1b[0m  1\nkanji 2\n\x1b[1;31m字\x1b[0m    3\n";
+        println!("\n{texts}");
+        assert_eq!(texts, txt_col);
+    }
 
-        assert_eq!(com_text, "primary\nsecondary\ntertiary\n");
+    #[test]
+    fn combine_narrower_than_cell_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).wrap_width(1);
+        let texts = cat_to_col.combine_col("字字\na", "1\n2").collect::<String>();
+        assert_eq!(texts, "字 1\n字\na  2\n");
+        let texts = cat_to_col.clone().boxed(true).combine_col("字", "1").collect::<String>();
+        assert_eq!(texts, "┌───┬─┐\n│字 │1│\n└───┴─┘\n");
+        let texts = CatToCol::new().combine_col_fixed("字字\na", "1\n2", 1, 1).collect::<String>();
+        assert_eq!(texts, " 1\na2\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn row_iter_tail() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let rows = cat_to_col.row_iter("one\nthree", "1\n3\n5\n7").collect::<Vec<_>>();
+        assert_eq!(rows.iter().map(Row::is_tail).collect::<Vec<_>>(), vec![false, false, true, true]);
+        assert_eq!((rows[1].left(), rows[1].right(), rows[1].width()), ("three", Some("3"), 7));
+        let texts = rows.iter().map(|row| format!("{row}\n")).collect::<String>();
+        assert_eq!(texts, cat_to_col.combine_col("one\nthree", "1\n3\n5\n7").collect::<String>());
+        let heads = cat_to_col.row_iter("one\nthree\nfive", "1").filter(|row| !row.is_tail()).count();
+        assert_eq!(heads, 3);
     }
 
     #[test]
-    fn test_by_three_lines_newlines() {
-        let iter = by_three_lines("\n\n\n\n", "\n\n\n\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_equal_columns_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).equal_columns(true);
+        let texts = cat_to_col.combine_col("three\none\nfive", "1\n33").collect::<String>();
+        assert_eq!(texts, "three.1....\none...33...\nfive.......\n");
+        assert!(texts.lines().all(|line| line.len() == 11));
+        let texts = cat_to_col.boxed(true).combine_col("one", "three").collect::<String>();
+        assert_eq!(texts, "┌──────┬─────┐\n│one...│three│\n└──────┴─────┘\n");
+    }
 
-        assert_eq!(com_text, "\n\n\n\n");
+    #[test]
+    fn column_builder_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).wrap_width(5);
+        let txt_one = "one\nthree two\nfive";
+        let mut builder = ColumnBuilder::new(&cat_to_col, txt_one);
+        let mut texts = builder.push_right_line("1");
+        texts.push_str(&builder.push_right_line("3"));
+        texts.push_str(&builder.finish());
+        assert_eq!(texts, cat_to_col.combine_col(txt_one, "1\n3").collect::<String>());
+        let mut builder = ColumnBuilder::new(&cat_to_col, "one");
+        let texts = ["1", "3", "5"].iter().map(|line| builder.push_right_line(line)).collect::<String>();
+        assert_eq!(texts + &builder.finish(), cat_to_col.combine_col("one", "1\n3\n5").collect::<String>());
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_split_cr_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).split_cr(true);
+        let texts = cat_to_col.combine_col("a\rbb\rc", "1\r\n2\n3\r").collect::<String>();
+        assert_eq!(texts, "a  1\nbb 2\nc  3\n");
+        assert_eq!(cat_to_col.combine_cols(&["a\rb", "1"]).collect::<String>(), "a 1\nb\n");
+        let texts = CatToCol::new().repeat(1).combine_col("a\rb", "1").collect::<String>();
+        assert_eq!(texts, "a\rb 1\n");
     }
 
     #[test]
-    fn test_by_three_lines_second_third_newline() {
-        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn with_titles_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.with_titles(&["Name", "Count"], &["one\nthree", "1\n3"]).collect::<String>();
+        assert_eq!(texts, "Name  Count\none   1\nthree 3\n");
+        let cat_to_col = cat_to_col.header_rule(Some('─'));
+        let texts = cat_to_col.with_titles(&["Description", "N"], &["one\nthree", "10\n3"]).collect::<String>();
+        assert_eq!(texts, "Description N\n─────────── ──\none         10\nthree       3\n");
+    }
 
-        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+    #[test]
+    fn combine_owned_txt() {
+        let rows = CatToCol::new()
+            .repeat(1)
+            .combine_owned(format!("{}\n{}", "one", "three"), format!("{}\n{}", 1, 3))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["one   1\n", "three 3\n"]);
+        let rows = CatToCol::new()
+            .repeat(1)
+            .row_separator("\r\n")
+            .block_terminator(Some("\r\n"))
+            .combine_owned(format!("{}\n{}", "one", "three"), format!("{}\n{}", 1, 3))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["one   1\r\n", "three 3\r\n"]);
+        let rows = CatToCol::new()
+            .repeat(1)
+            .row_separator(" | ")
+            .block_terminator(None)
+            .combine_owned(format!("{}\n{}", "one", "three"), format!("{}\n{}", 1, 3))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec!["one   1 | ", "three 3"]);
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn row_count_txt() {
+        let cat_to_col = CatToCol::new();
+        assert_eq!(cat_to_col.row_count("one\nthree", "1\n3\n"), 2);
+        assert_eq!(cat_to_col.row_count("one\nthree", "1\n3\n5\n7"), 4);
+        assert_eq!(cat_to_col.row_count("", ""), 0);
+        let cat_to_col = cat_to_col.wrap_width(5);
+        let txt_one = "one two three\nfour";
+        assert_eq!(cat_to_col.row_count(txt_one, "1"), 4);
+        assert_eq!(cat_to_col.row_count(txt_one, "1"), cat_to_col.combine_col(txt_one, "1").collect::<String>().lines().count());
+        assert_eq!(cat_to_col.boxed(true).row_count(txt_one, "1"), 6);
     }
 
     #[test]
-    fn test_by_three_lines_third_newline() {
-        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_cols_four_gutters() {
+        let cat_to_col = CatToCol::new().fill('|').repeat(2);
+        let txts = ["a\nbb", "ccc\nd", "e\nf", "g\nhhhh"];
+        let texts = cat_to_col.combine_cols(&txts).collect::<String>();
+        assert_eq!(texts, "a|||ccc||e||g\nbb||d||||f||hhhh\n");
+        for line in texts.lines() {
+            for start in [4, 9, 12] {
+                assert_eq!(&line[start - 2..start], "||");
+                assert_ne!(&line[start..start + 1], "|");
+            }
+        }
+    }
 
-        assert_eq!(com_text, "one first\ntwo second\nthree\nfour\n");
+    #[cfg(feature = "json")]
+    #[test]
+    fn rows_json_txt() {
+        let cat_to_col = CatToCol::new().fill('.');
+        let json = cat_to_col.rows_json("one\nthree", "1\n\"3\"", false);
+        assert_eq!(json, r#"[{"left":"one","right":"1"},{"left":"three","right":"\"3\""}]"#);
+        let value = serde_json::from_str::<serde_json::Value>(&cat_to_col.rows_json("one\nthree", "1", true)).unwrap();
+        assert_eq!(value[0]["left"], "one..");
+        assert_eq!(value[1]["right"], "");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_hanging_indent_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).wrap_width(8).hanging_indent(2);
+        let texts = cat_to_col.combine_col("- one two three\n- four", "1\n4").collect::<String>();
+        assert_eq!(texts, "- one...1\n  two\n  three\n- four..4\n");
+        let texts = cat_to_col.combine_cols(&["- one two", "1"]).collect::<String>();
+        assert_eq!(texts, "- one.1\n  two.\n");
     }
 
     #[test]
-    fn test_by_three_lines_first_third_newline() {
-        let iter = by_three_lines("\n\n\n", "first\nsecond\nthird\nfourth\nfifth\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_min_column_width_txt() {
+        let cat_to_col = CatToCol::new().min_column_width(10);
+        let texts = cat_to_col.combine_col("", "text\nmore").collect::<String>();
+        assert_eq!(texts, "          text\n          more\n");
+        assert!(texts.lines().all(|line| line.find('t').or(line.find('m')) == Some(10)));
+        let texts = cat_to_col.repeat(1).combine_col("one\nthree", "1\n3").collect::<String>();
+        assert_eq!(texts, "one        1\nthree      3\n");
+    }
 
-        assert_eq!(com_text, "first\nsecond\nthird\n");
+    #[test]
+    fn combine_range_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let txt_one = "one\ntwo\nthree\nfour\nfive";
+        let txt_two = "1\n2\n3\n4\n5";
+        let texts = cat_to_col.combine_col_range(txt_one, txt_two, 1..3).collect::<String>();
+        assert_eq!(texts, "two   2\nthree 3\n");
+        let all = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(texts, all.lines().skip(1).take(2).map(|line| format!("{line}\n")).collect::<String>());
+        assert_eq!(cat_to_col.combine_col_range(txt_one, txt_two, 4..9).collect::<String>(), "five  5\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_fill_cycle_txt() {
+        let cat_to_col = CatToCol::new().repeat(2).fill_cycle(&['.', ' ']);
+        let texts = cat_to_col.combine_col("one\nthree\nsix", "1\n3\n6").collect::<String>();
+        assert_eq!(texts, "one . .1\nthree .3\nsix . .6\n");
+        let cat_to_col = cat_to_col.fill_cycle(&[]).fill('-');
+        assert_eq!(cat_to_col.combine_col("one", "1").collect::<String>(), "one--1\n");
     }
 
+    fn assert_send_sync<T: Send + Sync>(_: T) {}
+
     #[test]
-    fn test_by_three_lines_second_newline() {
-        let iter = by_three_lines("one\ntwo\nthree\nfour\n", "\n\n", "primary\nsecondary\n");
-        let com_text = &iter.collect::<String>();
+    fn iterators_send_sync() {
+        let cat_to_col = CatToCol::new();
+        assert_send_sync(cat_to_col.combine_col("one", "1"));
+        assert_send_sync(cat_to_col.combine_col_esc("one", "1"));
+        assert_send_sync(cat_to_col.combine_col_fixed("one", "1", 3, 1));
+        assert_send_sync(cat_to_col.combine_col_cow("one", "1"));
+        assert_send_sync(cat_to_col.combine_col_range("one", "1", 0..1));
+        assert_send_sync(cat_to_col.combine_cols(&["one", "1"]));
+        assert_send_sync(cat_to_col.left_column("one"));
+        assert_send_sync(cat_to_col.row_iter("one", "1"));
+        assert_send_sync(cat_to_col.combine_owned(String::from("one"), String::from("1")));
+        assert_send_sync(free_iterators());
+    }
 
-        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
+    fn free_iterators() -> impl Iterator<Item = &'static str> + Send + Sync {
+        cat_to_col("one", "1")
+            .chain(by_lines("one", "1"))
+            .chain(by_pairs("one", "1"))
+            .chain(by_three_lines("one", "1", "I"))
+            .chain(by_four_lines("one", "1", "I", "i"))
+            .chain(interleave_lines("one", "1"))
+            .chain(annotate_lines("one", "1"))
+            .chain(by_columns_sep(&["one", "1"], &[" "]))
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_column_fractions_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).column_fractions_of(20, &[0.4, 0.6]);
+        let txt_one = "one two three four";
+        let txt_two = "a right column wider than the rest";
+        let texts = cat_to_col.combine_col(txt_one, txt_two).collect::<String>();
+        assert_eq!(texts, "one two a right colu\nthree\nfour\n");
+        assert_eq!(cat_to_col.plan(&[txt_one, txt_two]), vec![7, 12]);
+        let normalized = CatToCol::new().repeat(1).column_fractions_of(20, &[2.0, 3.0]);
+        assert_eq!(normalized.combine_col(txt_one, txt_two).collect::<String>(), texts);
     }
 
     #[test]
-    fn test_by_three_lines_first_skip_one() {
-        let iter = by_three_lines("one\n\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\nquaternary\n");
-        let com_text = &iter.collect::<String>();
+    fn align_on_str_txt() {
+        let cat_to_col = CatToCol::new();
+        let text = "foo = 1\nlongname = 2\n# none\nx = 3 = 4";
+        let aligned = cat_to_col.align_on_str(text, " = ", false).collect::<String>();
+        assert_eq!(aligned, "foo      = 1\nlongname = 2\n# none\nx        = 3 = 4\n");
+        let aligned = cat_to_col.fill('.').align_on_str(text, " = ", true).collect::<Vec<_>>();
+        assert_eq!(aligned[2], "# none..\n");
+    }
 
-        assert_eq!(com_text, "one first primary\nsecond secondary\nthree third tertiary\n");
+    #[test]
+    fn to_grid_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let grid = cat_to_col.to_grid("one\n字\nthree", "1\n2");
+        assert!(grid.iter().all(|row| row.len() == 7));
+        assert_eq!(grid[0], vec!['o', 'n', 'e', '.', '.', '.', '1']);
+        assert_eq!(grid[1], vec!['字', '\0', '.', '.', '.', '.', '2']);
+        assert_eq!(grid[2], vec!['t', 'h', 'r', 'e', 'e', '.', '.']);
+        assert!(cat_to_col.to_grid("", "").is_empty());
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_break_chars_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).break_chars(&['\x0c', '\x0b']);
+        let texts = cat_to_col.combine_col("one\x0cthree\r\nfive\x0b", "1\n3\n5").collect::<String>();
+        assert_eq!(texts, "one   1\nthree 3\nfive  5\n");
+        let texts = cat_to_col.split_cr(true).combine_col("one\rthree\x0cfive", "1").collect::<String>();
+        assert_eq!(texts, "one   1\nthree\nfive\n");
     }
 
     #[test]
-    fn test_by_three_lines_skip_one() {
-        let iter = by_three_lines("one\n\nthree\n", "\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\n");
-        let com_text = &iter.collect::<String>();
+    fn reset_options() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(3).row_prefix("> ");
+        let reset = cat_to_col.clone().reset_fill();
+        assert_eq!((reset.get_fill(), reset.get_repeat()), (" ", 3));
+        assert_eq!(reset.combine_col("one", "1").collect::<String>(), "> one   1\n");
+        let reset = cat_to_col.clone().reset_repeat();
+        assert_eq!(reset.combine_col("one", "1").collect::<String>(), "> one1\n");
+        assert_eq!(cat_to_col.with_defaults().fill('-'), CatToCol::new().fill('-'));
+    }
 
-        assert_eq!(com_text, "one primary\nsecond secondary\nthree third\n");
+    #[test]
+    fn combine_col_wrap_right_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.combine_col_wrap_right("term\nword", "a long definition\nshort", 10).collect::<String>();
 
-        println!("{:?}", com_text);
+        assert_eq!(texts, "term a long\n     definition\nword short\n");
     }
 
     #[test]
-    fn test_cat_four_lines_first_gt_second() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_line_numbers_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).line_numbers(true);
+        let txt_one = (1..=10).map(|num| format!("l{num}\n")).collect::<String>();
+        let txt_two = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\neleven\ntwelve\n";
+        let texts = cat_to_col.combine_col(&txt_one, txt_two).collect::<String>();
+        let lines = texts.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 12);
+        assert_eq!(lines[0], " 1  l1  one");
+        assert_eq!(lines[8], " 9  l9  nine");
+        assert_eq!(lines[9], "10  l10 ten");
+        assert_eq!(lines[10], "11      eleven");
+        assert_eq!(lines[11], "12      twelve");
+        assert_eq!(CatToCol::new().line_numbers(true).combine_col("one", "1").collect::<String>(), "1  one1\n");
+    }
 
-        assert_eq!(com_text, "one first primary uno\ntwo second secondary due\nthree tre\nfour quattro\n");
+    #[test]
+    fn combine_col3_first_gt_second() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.combine_col3("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n").collect::<String>();
 
-        println!("{:?}", com_text);
+        assert_eq!(texts, "one   first  primary\ntwo   second secondary\nthree\nfour\n");
     }
 
     #[test]
-    fn test_cat_four_lines_first_empty() {
-        let iter = by_four_lines("", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "");
+    fn combine_col3_first_eq_second() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.combine_col3("one\ntwo\nthree\n", "first\nsecond\nthird\n", "primary\nsecondary\ntertiary\n").collect::<String>();
 
-        println!("{:?}", com_text);
+        assert_eq!(texts, "one   first  primary\ntwo   second secondary\nthree third  tertiary\n");
     }
 
     #[test]
-    fn test_cat_four_lines_second_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
-
-        assert_eq!(com_text, "one primary uno\ntwo secondary due\nthree tre\nfour quattro\n");
+    fn combine_col3_first_lt_second() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.combine_col3("one\ntwo\nthree\n", "first\nsecond\nthird\nfourth\nfifth\n", "primary\nsecondary\ntertiary\nquaternary\n").collect::<String>();
 
-        println!("{:?}", com_text);
+        assert_eq!(texts, "one   first  primary\ntwo   second secondary\nthree third  tertiary\n      fourth quaternary\n      fifth\n");
     }
 
     #[test]
-    fn test_cat_four_lines_third_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_normalize_leading_whitespace_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).normalize_leading_whitespace(4);
+        let texts = cat_to_col.combine_col("fn main() {\n\tlet a;\n    let b;\n  \tlet c;\n}", "1\n\t2\n    3").collect::<String>();
 
-        assert_eq!(com_text, "one first uno\ntwo second due\nthree tre\nfour quattro\n");
+        assert_eq!(texts, "fn main() { 1\n    let a;      2\n    let b;      3\n    let c;\n}\n");
+        assert_eq!(cat_to_col.left_column("\ta\n    b").collect::<String>(), "    a\n    b\n");
+        assert_eq!(cat_to_col.normalize_leading_whitespace(0).validate(), Err(CatToColError::ZeroTabStop));
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_collapse_empty_left_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let padded = cat_to_col.combine_col("one\n\nthree", "1\n// two\n3\n4").collect::<String>();
+        assert_eq!(padded, "one   1\n      // two\nthree 3\n      4\n");
+        let collapsed = cat_to_col.collapse_empty_left(true).combine_col("one\n\nthree", "1\n// two\n3\n4").collect::<String>();
+        assert_eq!(collapsed, "one   1\n// two\nthree 3\n      4\n");
     }
 
     #[test]
-    fn test_cat_four_lines_fourth_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "");
-        let com_text = &iter.collect::<String>();
+    fn right_offsets_txt() {
+        let txt_one = "one\nthree\n\nseven";
+        let txt_two = "1\n3\n5\n7\n9";
+        let cat_to_col = CatToCol::new().repeat(2);
+        let offsets = cat_to_col.right_offsets(txt_one, txt_two).collect::<Vec<_>>();
+        assert_eq!(offsets, vec![5 + 2; 5]);
+        assert_eq!(cat_to_col.clone().row_prefix("> ").tab_align(4).right_offsets(txt_one, txt_two).collect::<Vec<_>>(), vec![8; 5]);
+        assert_eq!(cat_to_col.compact_tail(true).right_offsets(txt_one, txt_two).last(), Some(0));
+    }
 
-        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
+    #[test]
+    fn skip_empty_rows_never() {
+        let cat_to_col = CatToCol::new().repeat(1).skip_empty_rows(EmptyPolicy::Never);
+        let texts = cat_to_col.combine_col("one\n\nthree\n\nfive", "1\n\n\n4\n5\n6").collect::<String>();
+        assert_eq!(texts, "one   1\n      \nthree \n      4\nfive  5\n      6\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn skip_empty_rows_both_empty() {
+        let cat_to_col = CatToCol::new().repeat(1).skip_empty_rows(EmptyPolicy::BothEmpty);
+        let texts = cat_to_col.combine_col("one\n\nthree\n\nfive", "1\n\n\n4\n5\n6").collect::<String>();
+        assert_eq!(texts, "one   1\nthree \n      4\nfive  5\n      6\n");
     }
 
     #[test]
-    fn test_cat_four_lines_second_third_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn skip_empty_rows_right_empty() {
+        let cat_to_col = CatToCol::new().repeat(1).skip_empty_rows(EmptyPolicy::RightEmpty);
+        let texts = cat_to_col.combine_col("one\n\nthree\n\nfive", "1\n\n\n4\n5\n6").collect::<String>();
+        assert_eq!(texts, "one   1\n      4\nfive  5\n      6\n");
+    }
 
-        assert_eq!(com_text, "one uno\ntwo due\nthree tre\nfour quattro\n");
+    #[test]
+    fn skip_empty_rows_any_empty() {
+        let cat_to_col = CatToCol::new().repeat(1).skip_empty_rows(EmptyPolicy::AnyEmpty);
+        let texts = cat_to_col.combine_col("one\n\nthree\n\nfive", "1\n\n\n4\n5\n6").collect::<String>();
+        assert_eq!(texts, "one   1\nfive  5\n");
+        assert_eq!(cat_to_col.empty_cell("-").combine_col("one\n\nthree", "1").collect::<String>(), "one   1\nthree -\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn join_col_txt() {
+        let txt_one = "one\nthree\nfive";
+        let txt_two = "1\n3";
+        let cat_to_col = CatToCol::new().fill('|').repeat(1);
+        assert_eq!(cat_to_col.join_col(txt_one, txt_two).collect::<String>(), "one|1\nthree|3\nfive\n");
+        assert_eq!(cat_to_col.join_col(txt_two, txt_one).collect::<String>(), "1|one\n3|three\nfive\n");
+        assert_eq!(cat_to_col.combine_col(txt_one, txt_two).collect::<String>(), "one|||1\nthree|3\nfive\n");
+        assert_eq!(cat_to_col.fill(' ').join_col(txt_one, txt_two).collect::<String>(), super::cat_to_col(txt_one, txt_two).collect::<String>());
     }
 
     #[test]
-    fn test_cat_four_lines_second_third_fourth_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "", "");
-        let com_text = &iter.collect::<String>();
+    fn control_width_policies() {
+        let txt_one = "a\x07b\nxyz";
+        let txt_two = "1\n2";
+        let one = CatToCol::new().repeat(1);
+        assert_eq!(one.combine_col(txt_one, txt_two).collect::<String>(), "a\x07b 1\nxyz 2\n");
+        let zero = one.clone().control_width(ControlPolicy::Zero);
+        assert_eq!(zero.combine_col(txt_one, txt_two).collect::<String>(), "a\x07b  1\nxyz 2\n");
+        let escape = one.control_width(ControlPolicy::Escape);
+        assert_eq!(escape.combine_col(txt_one, txt_two).collect::<String>(), "a^Gb 1\nxyz  2\n");
+        assert_eq!(escape.combine_col("\x7f\x1b", "\x00").collect::<String>(), "^?^[ ^@\n");
+        assert_eq!(escape.combine_col_esc("\x1b[1ma\x1b[0m\x07", "1").collect::<String>(), "\x1b[1ma\x1b[0m^G 1\n");
+        let escape = escape.fill('.');
+        assert_eq!(escape.combine_col_esc(txt_one, txt_two).collect::<String>(), "a^Gb.1\nxyz..2\n");
+        assert_eq!(escape.combine_col_esc("\x1b[2ma\x07b\x1b[0m\nxyz", txt_two).collect::<String>(), "\x1b[2ma^Gb\x1b[0m.1\nxyz..2\n");
+        let zero = zero.fill('.');
+        assert_eq!(zero.combine_col_esc(txt_one, txt_two).collect::<String>(), "a\x07b..1\nxyz.2\n");
+    }
 
-        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+    #[test]
+    fn combine_col_into_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let mut buf = String::new();
+        cat_to_col.combine_col_into("one\nthree\nfive", "1\n3\n5", &mut buf);
+        assert_eq!(buf, "one   1\nthree 3\nfive  5\n");
+        let capacity = buf.capacity();
+        cat_to_col.combine_col_into("two", "2", &mut buf);
+        assert_eq!(buf, "two 2\n");
+        assert_eq!(buf.capacity(), capacity);
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_align_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let right = cat_to_col.clone().align(Align::Right).combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(right, "..one.1\nthree.3\n.five\n");
+        let center = cat_to_col.align(Align::Center).combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(center, ".one..1\nthree.3\nfive\n");
     }
 
     #[test]
-    fn test_cat_four_lines_second_fourth_empty() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "", "primary\nsecondary\n", "");
-        let com_text = &iter.collect::<String>();
+    fn combine_esc_align_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).align(Align::Right);
+        let texts = cat_to_col.combine_col_esc("\x1b[31mab\x1b[0m\nabcd", "1\n2").collect::<String>();
+        assert_eq!(texts, "  \x1b[31mab\x1b[0m 1\nabcd 2\n");
+        let visible = String::from_utf8(strip(&texts).unwrap()).unwrap();
+        assert_eq!(visible, "  ab 1\nabcd 2\n");
+    }
 
-        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
+    #[test]
+    fn combine_col_tree_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let level = |line: &str| line.len() - line.trim_start_matches('#').len();
+        let texts = cat_to_col.combine_col_tree("#one\n##two\n##three\n#four", "1\n2\n3\n4\n5", level).collect::<String>();
+        assert_eq!(texts, "  #one      1\n    ##two   2\n    ##three 3\n  #four     4\n            5\n");
+        let texts = cat_to_col.indent_width(1).combine_col_tree("a\nb", "1\n2", |line| usize::from(line == "b")).collect::<String>();
+        assert_eq!(texts, "a  1\n b 2\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_cols_n_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).wrap_width(5);
+        let texts = ["one\nthree", "1\n3\n5", "a long line", "x\n\ny"];
+        assert_eq!(cat_to_col.combine_cols_n([texts[0], texts[1]]).collect::<String>(), cat_to_col.combine_cols(&texts[..2]).collect::<String>());
+        assert_eq!(cat_to_col.combine_cols_n([texts[0], texts[1], texts[2]]).collect::<String>(), cat_to_col.combine_cols(&texts[..3]).collect::<String>());
+        assert_eq!(cat_to_col.combine_cols_n(texts).collect::<String>(), cat_to_col.combine_cols(&texts).collect::<String>());
+        assert_eq!(cat_to_col.combine_cols_n(texts).collect::<String>(), "one...1.a....x\n........long.\n........line.\nthree.3......\n......5......y\n");
     }
 
     #[test]
-    fn test_cat_four_lines_first_newline() {
-        let iter = by_four_lines("\n\n\n\n", "first\nsecond\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_blocks_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let texts = cat_to_col.combine_blocks("one\nthree\nfive\nseven", "1\n33\n5").collect::<String>();
+        assert_eq!(texts, "one...1.\nthree.33\nfive..5.\nseven...\n");
+        let texts = cat_to_col.combine_blocks("one\nthree", "1\n33\n5\n").collect::<String>();
+        assert_eq!(texts, "one...1.\nthree.33\n......5.\n");
+    }
 
-        assert_eq!(com_text, "first primary uno\nsecond secondary due\ntre\nquattro\n");
+    #[test]
+    fn combine_sanitize_cells_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).sanitize_cells(true);
+        let texts = cat_to_col.combine_col("one\rtwo\nthree", "1\n3\x1b").collect::<String>();
+        assert_eq!(texts, "one two 1\nthree   3 \n");
+        let texts = cat_to_col.combine_col_esc("\x1b[1mone\x1b[0m\rtwo", "1").collect::<String>();
+        assert_eq!(texts, "\x1b[1mone\x1b[0m two 1\n");
+        let cat_to_col = cat_to_col.fill('.');
+        let texts = cat_to_col.combine_col_esc("a\rb\nxyz", "1\n2").collect::<String>();
+        assert_eq!(texts, "a b.1\nxyz.2\n");
+        let texts = cat_to_col.combine_col_esc("a\x1bb\n\x1b[2mxyz\x1b[0m", "1\n2").collect::<String>();
+        assert_eq!(texts, "a b.1\n\x1b[2mxyz\x1b[0m.2\n");
+        let texts = cat_to_col.combine_col_esc("ab\x1b[\nxyz\x1b]8;;", "1\n2").collect::<String>();
+        assert_eq!(texts, "ab [.....1\nxyz ]8;;.2\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn line_widths_txt() {
+        let cat_to_col = CatToCol::new();
+        assert_eq!(cat_to_col.line_widths("one\n字字字\n").collect::<Vec<_>>(), vec![3, 6]);
+        assert_eq!(cat_to_col.line_widths("one\n\nthree").collect::<Vec<_>>(), vec![3, 0, 5]);
+        let chars = cat_to_col.width_mode(WidthMode::Chars);
+        assert_eq!(chars.line_widths("one\n字字字\nthree").collect::<Vec<_>>(), vec![3, 3, 5]);
     }
 
     #[test]
-    fn test_cat_four_lines_first_second_newline() {
-        let iter = by_four_lines("\n\n\n\n", "\n\n", "primary\nsecondary\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn truncate_with_middle() {
+        let path = "/home/user/projects/cattocol/src/lib.rs";
+        let truncated = truncate_with(path, 16, "…", TruncateMode::Middle);
+        assert_eq!(truncated, "/home/us…/lib.rs");
+        assert_eq!(truncated.width(), 16);
+        assert_eq!(truncate_with(path, 15, "…", TruncateMode::Middle), "/home/u…/lib.rs");
+        assert_eq!(truncate_with("北風が吹く", 7, "…", TruncateMode::Middle), "北風…く");
+        assert_eq!(truncate_with("wind", 4, "…", TruncateMode::Middle), "wind");
+    }
 
-        assert_eq!(com_text, "primary uno\nsecondary due\ntre\nquattro\n");
+    #[test]
+    fn display_width_no_esc_strip() {
+        let texts = [
+            "plain",
+            "\x1b[31mred\x1b[0m",
+            "\x1b[1;4;38;5;208mbold\x1b[22m and \x1b[0mreset",
+            "\x1b]8;;https://example.com\x07link\x1b]8;;\x07 text",
+            "\x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\",
+            "字\x1b[7m字\x1b[27m字",
+            "tab\there\x07",
+            "one\n\x1b[2mthree\x1b[0m\nfive",
+            "\x1b[0m",
+        ];
+        for text in texts {
+            let stripped = String::from_utf8(strip(text).unwrap()).unwrap();
+            let expected = stripped.lines().map(UnicodeWidthStr::width).max().unwrap_or(0);
+            assert_eq!(display_width_no_esc(text), expected, "{text:?}");
+        }
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_col_with_fn_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let rows = cat_to_col.combine_col_with_fn("one\nthree\nfive", |index, _| format!("row {index}")).collect::<Vec<_>>();
+        assert_eq!(rows, vec!["one   row 0\n", "three row 1\n", "five  row 2\n"]);
+        let mut calls = 0;
+        let first = cat_to_col.combine_col_with_fn("one\nthree", |_, line| { calls += 1; line.len().to_string() }).next();
+        assert_eq!(first.as_deref(), Some("one   3\n"));
+        assert_eq!(calls, 1);
     }
 
     #[test]
-    fn test_cat_four_lines_first_second_third_fourth_newline() {
-        let iter = by_four_lines("\n\n\n\n", "\n\n", "\n\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_row_separator_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).block_terminator(None);
+        assert_eq!(cat_to_col.combine_col("a\nb\nc", "").collect::<String>(), "a\nb\nc");
+        let cat_to_col = cat_to_col.row_separator(" | ");
+        assert_eq!(cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>(), "one   1 | three 3");
+        assert_eq!(cat_to_col.combine_col_range("one\nthree\nfive", "1\n3\n5", 1..2).collect::<String>(), "three 3 | ");
+        let cat_to_col = cat_to_col.row_separator("");
+        assert_eq!(cat_to_col.combine_col_range("one\nthree\nfive", "1\n3\n5", 1..2).collect::<String>(), "three 3");
+        assert_eq!(cat_to_col.combine_col_buffer("one\nthree", "1\n3").lines().collect::<Vec<_>>(), ["one   1", "three 3"]);
+        assert_eq!(cat_to_col.clone().combine_owned("one\nthree".to_string(), "1\n3".to_string()).collect::<Vec<_>>(), ["one   1", "three 3"]);
+        let cat_to_col = CatToCol::new().repeat(1).block_terminator(Some("\n\n"));
+        assert_eq!(cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>(), "one   1\nthree 3\n\n");
+        assert_eq!(cat_to_col.combine_cols(&["one\nthree", "1\n3", "x"]).collect::<String>(), "one   1 x\nthree 3\n\n");
+        assert_eq!(cat_to_col.combine_col("", "").collect::<String>(), "");
+    }
 
-        assert_eq!(com_text, "\n\n\n\n");
+    #[test]
+    fn combine_col_measured_txt() {
+        for cat_to_col in [CatToCol::new().repeat(2), CatToCol::new().repeat(1).row_prefix("> ").wrap_width(4), CatToCol::new().boxed(true)] {
+            let combined = cat_to_col.combine_col_measured("one\nthree\nfive", "1\n3\n5\n7");
+            assert_eq!(combined.text, cat_to_col.combine_col("one\nthree\nfive", "1\n3\n5\n7").collect::<String>());
+            assert_eq!(combined.width, combined.text.lines().map(UnicodeWidthStr::width).max().unwrap_or(0));
+            assert_eq!(combined.rows, combined.text.lines().count());
+        }
+        assert_eq!(CatToCol::new().combine_col_measured("", ""), Combined::default());
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_tsv_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.combine_tsv("one\t1\nthree\t33\nfive", "first\nsecond\nthird\nfourth").collect::<String>();
+        assert_eq!(texts, "one   1  first\nthree 33 second\nfive     third\n         fourth\n");
+        let texts = CatToCol::new().fill('.').repeat(1).combine_tsv("a\tbb\nccc\td", "1").collect::<String>();
+        assert_eq!(texts, "a...bb.1\nccc.d\n");
     }
 
     #[test]
-    fn test_cat_four_lines_fourth_newline() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_col_iter_field() {
+        struct Pager<'a> {
+            rows: CombineColIter<'a>,
+        }
+        let cat_to_col = CatToCol::new().repeat(1);
+        let mut pager = Pager { rows: cat_to_col.combine_col("one\nthree\nfive", "1\n3\n5") };
+        let row_len = cat_to_col.combine_col("one\nthree\nfive", "1\n3\n5").position(|fragment| fragment == "\n").unwrap() + 1;
+        assert_eq!(pager.rows.by_ref().take(row_len).collect::<String>(), "one   1\n");
+        assert_eq!(pager.rows.collect::<String>(), "three 3\nfive  5\n");
+    }
 
-        assert_eq!(com_text, "one first primary\ntwo second secondary\nthree\nfour\n");
+    #[test]
+    fn combine_width_strategy_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let fixed = cat_to_col.clone().width_strategy(WidthStrategy::Fixed(4));
+        assert_eq!(fixed.combine_col("one\nthree\nfive", "1\n3\n5\n7").collect::<String>(), "one..1\nthree.3\nfive.5\n.....7\n");
+        let running = cat_to_col.width_strategy(WidthStrategy::Running);
+        assert_eq!(running.combine_col("one\nfive\nthree\ntwo", "1\n5\n3\n2").collect::<String>(), "one.1\nfive.5\nthree.3\ntwo...2\n");
+        assert_eq!(running.rows("one\nfive", "1\n5"), vec![("one".to_string(), "1".to_string()), ("five".to_string(), "5".to_string())]);
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_width_strategy_streaming() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let first_row = |cat_to_col: &CatToCol, txt_one| cat_to_col.combine_col(txt_one, "1\n3").take_while(|fragment| *fragment != "\n").collect::<String>();
+        let txt_one = "one\nthree three three";
+        assert_eq!(first_row(&cat_to_col, txt_one), "one...............1");
+        let fixed = cat_to_col.clone().width_strategy(WidthStrategy::Fixed(4));
+        assert_eq!(first_row(&fixed, txt_one), "one..1");
+        assert_eq!(first_row(&fixed, txt_one), first_row(&fixed, "one"));
+        let running = cat_to_col.width_strategy(WidthStrategy::Running);
+        assert_eq!(first_row(&running, txt_one), "one.1");
+        assert_eq!(first_row(&running, txt_one), first_row(&running, "one"));
+        assert_eq!(running.combine_col(txt_one, "1\n3").collect::<String>(), "one.1\nthree three three.3\n");
     }
 
     #[test]
-    fn test_cat_four_lines_third_fourth_newline() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "\n\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_esc_cell_bg_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).cell_bg("\x1b[44m");
+        let texts = cat_to_col.combine_col_esc("\x1b[31mone\x1b[0m\nthree", "1\n3").collect::<String>();
+        assert_eq!(texts, "\x1b[44m\x1b[31mone\x1b[0m\x1b[44m...\x1b[0m1\n\x1b[44mthree\x1b[44m.\x1b[0m3\n");
+        for row in texts.lines() {
+            let cell = row.strip_prefix("\x1b[44m").unwrap().rsplit_once("\x1b[0m").unwrap().0;
+            assert_eq!(display_width_no_esc(cell), 6);
+        }
+        assert_eq!(cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>(), "one...1\nthree.3\n");
+    }
 
-        assert_eq!(com_text, "one first\ntwo second\nthree\nfour\n");
+    #[test]
+    fn combine_pairs_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let pairs = [("name", "cattocol"), ("version", "0.3.1"), ("license", "GPL-3.0")];
+        assert_eq!(cat_to_col.combine_pairs(&pairs), "name....cattocol\nversion.0.3.1\nlicense.GPL-3.0\n");
+        assert_eq!(cat_to_col.combine_pairs(&pairs), cat_to_col.combine_col("name\nversion\nlicense", "cattocol\n0.3.1\nGPL-3.0").collect::<String>());
+        assert_eq!(cat_to_col.combine_pairs(&[]), "");
+        let pairs = [("authors", "one\ntwo"), ("name\nof crate", "cattocol"), ("", "GPL-3.0"), ("version", "0.3.1")];
+        assert_eq!(cat_to_col.combine_pairs(&pairs), "authors..one\n.........two\nname.....cattocol\nof crate.\n.........GPL-3.0\nversion..0.3.1\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn write_combine_col_encoded_txt() {
+        let cat_to_col = CatToCol::new().fill('·').repeat(1);
+        let mut identity = Vec::new();
+        cat_to_col.write_combine_col_encoded(&mut identity, "café\nthé", "1\n2\n3", |text| text.as_bytes().to_vec()).unwrap();
+        assert_eq!(identity, cat_to_col.combine_col("café\nthé", "1\n2\n3").collect::<String>().into_bytes());
+        let mut latin1 = Vec::new();
+        cat_to_col.write_combine_col_encoded(&mut latin1, "café\nthé", "1\n2\n3", |text| text.chars().map(|chr| u8::try_from(chr).unwrap_or(b'?')).collect()).unwrap();
+        assert_eq!(latin1, b"caf\xe9\xb71\nth\xe9\xb7\xb72\n\xb7\xb7\xb7\xb7\xb73\n");
     }
- 
+
     #[test]
-    fn test_cat_four_lines_second_third_fourth_newline() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_gutter_if_empty_right_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).gutter_if_empty_right(Some(""));
+        assert_eq!(cat_to_col.combine_col("one\nthree\nfive", "1\n\n5").collect::<String>(), "one...1\nthree\nfive..5\n");
+        assert_eq!(cat_to_col.right_offsets("one\nthree\nfive", "1\n\n5").collect::<Vec<_>>(), [6, 5, 6]);
+        let cat_to_col = CatToCol::new().fill('.').repeat(1).gutter_if_empty_right(Some(" -"));
+        assert_eq!(cat_to_col.combine_col("one\nthree\nfive", "1\n\n5").collect::<String>(), "one...1\nthree -\nfive..5\n");
+        assert_eq!(CatToCol::new().fill('.').repeat(1).combine_col("one\nthree\nfive", "1\n\n5").collect::<String>(), "one...1\nthree.\nfive..5\n");
+        let cat_to_col = cat_to_col.gutter_if_empty_right(None);
+        assert_eq!(cat_to_col.combine_col("one\nthree\nfive", "1\n\n5").collect::<String>(), "one...1\nthree.\nfive..5\n");
+    }
 
-        assert_eq!(com_text, "one\ntwo\nthree\nfour\n");
+    #[test]
+    fn combine_border_mode_txt() {
+        let dense = CatToCol::new().repeat(1).boxed(true);
+        let texts = dense.combine_col("one\n\nfive\nseven", "1\n2\n\n7\n9").collect::<String>();
+        assert_eq!(texts, "┌──────┬─┐\n│one   │1│\n│      │2│\n│five  │ │\n│seven │7│\n│      │9│\n└──────┴─┘\n");
+        let sparse = dense.border_mode(BorderMode::Sparse);
+        let texts = sparse.combine_col("one\n\nfive\nseven", "1\n2\n\n7\n9").collect::<String>();
+        assert_eq!(texts, "┌──────┬─┐\n│one   │1│\n│       2│\n│five    │\n│seven │7│\n│       9│\n└──────┴─┘\n");
+        let texts = sparse.combine_col_esc("one\nfive", "1").collect::<String>();
+        assert_eq!(texts, "┌─────┬─┐\n│one  \x1b[0m│1\x1b[0m│\n│five \x1b[0m  \x1b[0m│\n└─────┴─┘\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_tab_stops_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).tab_stops(8);
+        assert_eq!(cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>(), "one     1\nthree   3\n");
+        assert_eq!(cat_to_col.combine_col("a longer line\nfive", "1\n5").collect::<String>(), "a longer line   1\nfive            5\n");
+        assert_eq!(cat_to_col.combine_col("seven..\nfive", "7\n5").collect::<String>(), "seven.. 7\nfive    5\n");
+        assert_eq!(cat_to_col.clone().row_prefix("> ").right_offsets("one\nthree", "1\n3").collect::<Vec<_>>(), [8, 8]);
+        assert_eq!(cat_to_col.repeat(0).combine_col("eight...\none", "8\n1").collect::<String>(), "eight...8\none     1\n");
     }
 
     #[test]
-    fn test_cat_four_lines_second_fourth_newline() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "primary\nsecondary\n", "\n\n\n\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_nbsp_fill_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = cat_to_col.clone().nbsp_fill(true).combine_col("one\nthree", "1\n3").collect::<String>();
+        assert_eq!(texts.as_bytes(), b"one\xc2\xa0\xc2\xa0\xc2\xa01\nthree\xc2\xa03\n");
+        assert_eq!(texts.replace('\u{a0}', " "), cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>());
+        assert_eq!(texts.lines().map(UnicodeWidthStr::width).collect::<Vec<_>>(), [7, 7]);
+        let texts = CatToCol::new().fill('.').repeat(1).nbsp_fill(true).combine_col("one\nthree", "1\n3").collect::<String>();
+        assert_eq!(texts, "one...1\nthree.3\n");
+    }
 
-        assert_eq!(com_text, "one primary\ntwo secondary\nthree\nfour\n");
+    #[test]
+    fn combine_cols_balance_to_txt() {
+        let cat_to_col = CatToCol::new().repeat(1).balance_to(40);
+        let wide = "The first column has the long lines of text that need wrapping.\nA short one.";
+        let narrow = "The narrow column\nof the text.";
+        assert_eq!(cat_to_col.plan(&[wide, narrow]), [30, 9]);
+        let texts = cat_to_col.combine_cols(&[wide, narrow]).collect::<String>();
+        assert_eq!(texts, "The first column has the long  The\nlines of text that need        narrow\nwrapping.                      column\nA short one.                   of the\n                               text.\n");
+        assert!(texts.lines().all(|line| line.width() <= 40));
+        assert_eq!(cat_to_col.combine_cols_n([wide, narrow]).collect::<String>(), texts);
+        assert_eq!(CatToCol::new().repeat(1).balance_to(40).plan(&["one", "two"]), [19, 20]);
+        let texts = cat_to_col.combine_col(wide, narrow).collect::<String>();
+        assert_eq!(texts, "The first column has the long The\nlines of text that need       narrow\nwrapping.                     column\nA short one.                  of the\n                              text.\n");
+        let texts = CatToCol::new().repeat(1).balance_to(10).combine_col("one two three four", "1").collect::<String>();
+        assert_eq!(texts, "one two 1\nthree\nfour\n");
+        assert!(texts.lines().all(|line| line.width() <= 10));
+        let texts = CatToCol::new().repeat(1).balance_to(12).combine_col_esc("\x1b[1mone\x1b[0m", "one two three").collect::<String>();
+        assert_eq!(texts, "\x1b[1mon\x1b[0m one two\n\x1b[1me\x1b[0m  three\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_tail_policy_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let texts = cat_to_col.clone().tail_policy(TailPolicy::Blank).combine_col("one\nthree\nfive", "1").collect::<String>();
+        assert_eq!(texts, "one...1\nthree\nfive\n");
+        let texts = cat_to_col.clone().tail_policy(TailPolicy::RepeatLast).combine_col("one\nthree\nfive", "1").collect::<String>();
+        assert_eq!(texts, "one...1\nthree.1\nfive..1\n");
+        let texts = cat_to_col.clone().tail_policy(TailPolicy::RepeatLast).combine_col("label", "1\n2\n3").collect::<String>();
+        assert_eq!(texts, "label.1\nlabel.2\nlabel.3\n");
+        let texts = cat_to_col.clone().tail_policy(TailPolicy::Truncate).combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(texts, "one...1\nthree.3\n");
+        let texts = cat_to_col.tail_policy(TailPolicy::RepeatLast).combine_col("", "1\n2").collect::<String>();
+        assert_eq!(texts, ".1\n.2\n");
     }
 
     #[test]
-    fn test_cat_four_lines_first_thirdd_newline() {
-        let iter = by_four_lines("\n\n\n\n", "first\nsecond\n", "\n\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_rotated_txt() {
+        let cat_to_col = CatToCol::new().fill('.');
+        assert_eq!(cat_to_col.combine_blocks("ab\ncd", "e\nf").collect::<String>(), "abe\ncdf\n");
+        let rotated = cat_to_col.combine_rotated("ab\ncd", "e\nf");
+        assert_eq!(rotated, "ac\nbd\nef\n");
+        assert_eq!((rotated.lines().count(), rotated.lines().map(str::len).max()), (3, Some(2)));
+        assert_eq!(cat_to_col.combine_rotated("one\nthree", "1"), "ot\nnh\ner\n.e\n.e\n1.\n");
+        assert_eq!(cat_to_col.combine_rotated("", ""), "");
+        let rotated = cat_to_col.combine_rotated("字b\ncd", "e");
+        assert_eq!(rotated, "字c\n..d\nb..\ne..\n");
+        assert!(rotated.lines().all(|line| line.width() == 3));
+    }
 
-        assert_eq!(com_text, "first uno\nsecond due\ntre\nquattro\n");
+    #[test]
+    fn combine_detect_ansi_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let colored = "\x1b[31mone\x1b[0m\nthree";
+        let texts = cat_to_col.combine_col(colored, "1\n3").collect::<String>();
+        assert_eq!(texts, "\x1b[31mone\x1b[0m.1\nthree........3\n");
+        let cat_to_col = cat_to_col.detect_ansi(true);
+        let texts = cat_to_col.combine_col(colored, "1\n3").collect::<String>();
+        assert_eq!(texts, cat_to_col.combine_col_esc(colored, "1\n3").collect::<String>());
+        assert_eq!(strip(texts).unwrap(), b"one...1\nthree.3\n");
+        assert_eq!(cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>(), "one...1\nthree.3\n");
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn print_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let mut writer = Vec::new();
+        cat_to_col.write_combine_col(&mut writer, "one\nthree", "1\n3").unwrap();
+        assert_eq!(writer, cat_to_col.combine_col("one\nthree", "1\n3").collect::<String>().into_bytes());
+        let mut writer = Vec::new();
+        cat_to_col.write_combine_col_esc(&mut writer, "\x1b[1mone\x1b[0m\nthree", "1\n3").unwrap();
+        assert_eq!(writer, cat_to_col.combine_col_esc("\x1b[1mone\x1b[0m\nthree", "1\n3").collect::<String>().into_bytes());
+        assert!(cat_to_col.print("one\nthree", "1\n3").is_ok());
+        assert!(cat_to_col.print_esc("\x1b[1mone\x1b[0m\nthree", "1\n3").is_ok());
+        assert!(cat_to_col.eprint("one\nthree", "1\n3").is_ok());
     }
 
     #[test]
-    fn test_cat_four_lines_second_third() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "\n\n", "\n\n", "uno\ndue\ntre\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn combine_width_overrides_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        assert_eq!(cat_to_col.combine_col("ab、\nthree", "1\n3").collect::<String>(), "ab、..1\nthree.3\n");
+        let cat_to_col = cat_to_col.width_overrides(&[('、', 1)]);
+        assert_eq!(cat_to_col.combine_col("ab、\nthree", "1\n3").collect::<String>(), "ab、...1\nthree.3\n");
+        let cat_to_col = cat_to_col.width_overrides(&[('→', 2)]);
+        assert_eq!(cat_to_col.combine_col("a→b\nthree", "1\n3").collect::<String>(), "a→b..1\nthree.3\n");
+        assert_eq!(cat_to_col.combine_col_esc("\x1b[1ma→b\x1b[0m\nthree", "1\n3").collect::<String>(), "\x1b[1ma→b\x1b[0m..1\nthree.3\n");
+    }
 
-        assert_eq!(com_text, "one uno\ntwo due\nthree tre\nfour quattro\n");
+    #[test]
+    fn combine_col_buffer_txt() {
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let buffer = cat_to_col.combine_col_buffer("one\nthree\nfive", "1\n3");
+        let texts = cat_to_col.combine_col("one\nthree\nfive", "1\n3").collect::<String>();
+        assert_eq!(buffer.as_str(), texts);
+        assert_eq!(buffer.lines().collect::<Vec<_>>(), texts.split_terminator('\n').collect::<Vec<_>>());
+        assert_eq!(buffer.lines().collect::<Vec<_>>(), ["one...1", "three.3", "five"]);
+        let cat_to_col = cat_to_col.row_separator("\r\n").block_terminator(None);
+        let buffer = cat_to_col.combine_col_buffer("one\nthree", "1\n3");
+        assert_eq!(buffer.lines().collect::<Vec<_>>(), ["one...1", "three.3"]);
+        assert_eq!(buffer.into_string(), "one...1\r\nthree.3");
+        assert_eq!(CatToCol::new().combine_col_buffer("", ""), CombinedBuffer::default());
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn cat_to_col_aligned_txt() {
+        let texts = cat_to_col_aligned("one\nthree", "1\n3\n5\n7").collect::<String>();
+        assert_eq!(texts, "one 1\nthree 3\n      5\n      7\n");
+        assert_eq!(super::cat_to_col("one\nthree", "1\n3\n5\n7").collect::<String>(), "one 1\nthree 3\n5\n7\n");
+        assert_eq!(cat_to_col_aligned("one\nthree\nfive", "1").collect::<String>(), "one 1\nthree\nfive\n");
+        assert_eq!(cat_to_col_aligned("", "1\n3").collect::<String>(), "1\n3\n");
     }
 
     #[test]
-    fn test_cat_four_lines_fourth_skip() {
-        let iter = by_four_lines("one\ntwo\nthree\nfour\n", "first\nsecond\n", "primary\nsecondary\n", "uno\n\n\nquattro\n");
-        let com_text = &iter.collect::<String>();
+    fn fits_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        assert!(cat_to_col.fits("one\nthree", "1\n33", 8));
+        assert!(!cat_to_col.fits("one\nthree", "1\n33", 7));
+        let cat_to_col = CatToCol::new().repeat(4);
+        assert!(!cat_to_col.fits("one\nthree", "1\n33", 8));
+        assert!(cat_to_col.fits("one\nthree", "1\n33", 11));
+        assert_eq!(cat_to_col.combine_col_measured("one\nthree", "1\n33").width, 11);
+        assert!(CatToCol::new().fits("", "", 0));
+    }
 
-        assert_eq!(com_text, "one first primary uno\ntwo second secondary\nthree\nfour quattro\n");
+    #[test]
+    fn combine_cols_fill_txt() {
+        let cat_to_col = CatToCol::new().repeat(1);
+        let texts = ["one\nthree", "1\n33", "I\nIII", "end"];
+        assert_eq!(cat_to_col.combine_cols_fill(&texts, &['.', '-', '_']), "one...1--I___end\nthree.33-III\n");
+        assert_eq!(cat_to_col.combine_cols_fill(&texts, &['.']), "one...1  I   end\nthree.33 III\n");
+        assert_eq!(cat_to_col.combine_cols_fill(&texts, &[]), cat_to_col.combine_cols(&texts).collect::<String>());
+    }
 
-        println!("{:?}", com_text);
+    #[test]
+    fn combine_fill_fn_nbsp_fill_txt() {
+        let fill_fn = |index| if index == 0 { '.' } else { '-' };
+        let cat_to_col = CatToCol::new().fill(' ').repeat(3);
+        assert_eq!(cat_to_col.combine_col_fill_fn("a\nbbb", "x\ny", fill_fn).collect::<String>(), "a.....x\nbbb---y\n");
+        assert_eq!(cat_to_col.combine_col_cow("a\nbbb", "x\ny").count(), 12);
+        let cat_to_col = cat_to_col.nbsp_fill(true);
+        assert_eq!(cat_to_col.combine_col_fill_fn("a\nbbb", "x\ny", fill_fn).collect::<String>(), "a.....x\nbbb---y\n");
+        let texts = cat_to_col.combine_col_cow("a\nbbb", "x\ny").collect::<Vec<_>>();
+        assert_eq!(texts.len(), 12);
+        assert_eq!(texts[2], "\u{a0}".repeat(5));
+    }
+
+    #[test]
+    fn width_overrides_zwj_txt() {
+        let family = "👨\u{200d}👩\u{200d}👧";
+        let cat_to_col = CatToCol::new().fill('.').repeat(1);
+        let texts = cat_to_col.combine_col(family, "1\n2").collect::<String>();
+        let overridden = cat_to_col.width_overrides(&[('👨', 1), ('👩', 1), ('👧', 1)]);
+        assert_eq!(overridden.combine_col(family, "1\n2").collect::<String>(), texts);
+        assert_eq!(overridden.combine_col("👨\n👩👧", "1\n2").collect::<String>(), "👨..1\n👩👧.2\n");
     }
 }